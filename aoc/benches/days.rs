@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use aoc::inputs;
+use common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+
+/// One group per day, timing parse, part 1 and part 2 on every input set.
+fn bench<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group
+        .sampling_mode(SamplingMode::Flat)
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2));
+    for set in inputs::sets() {
+        let Some(input) = inputs::load(&set, S::DAY) else {
+            continue;
+        };
+        let parsed = S::parse(&input).unwrap();
+        group.bench_function(format!("{}/parse", set), |b| {
+            b.iter(|| S::parse(black_box(&input)).unwrap())
+        });
+        group.bench_function(format!("{}/part1", set), |b| {
+            b.iter(|| S::part1(black_box(&parsed)))
+        });
+        group.bench_function(format!("{}/part2", set), |b| {
+            b.iter(|| S::part2(black_box(&parsed)))
+        });
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    // Input paths are relative to the repository root.
+    std::env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).unwrap();
+    bench::<day1::Day1>(c);
+    bench::<day2::Day2>(c);
+    bench::<day3::Day3>(c);
+    bench::<day4::Day4>(c);
+    bench::<day5::Day5>(c);
+    bench::<day6::Day6>(c);
+    bench::<day7::Day7>(c);
+    bench::<day8::Day8>(c);
+    bench::<day9::Day9>(c);
+    bench::<day10::Day10>(c);
+    bench::<day11::Day11>(c);
+    bench::<day12::Day12>(c);
+    bench::<day13::Day13>(c);
+    bench::<day14::Day14>(c);
+    bench::<day15::Day15>(c);
+    bench::<day16::Day16>(c);
+    bench::<day17::Day17>(c);
+    bench::<day18::Day18>(c);
+    bench::<day19::Day19>(c);
+    bench::<day20::Day20>(c);
+    bench::<day21::Day21>(c);
+    bench::<day22::Day22>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use common::read_input;
+use std::{fs, path::PathBuf};
+
+/// Input sets stored in the day directories: the puzzle examples in
+/// `dayN/test_case` and our own puzzle inputs in `dayN/input`.
+pub const BUILTIN: [&str; 2] = ["example", "real"];
+
+/// Directory of the named input sets, one `inputs/<name>/dayN.txt` per day.
+pub const DIR: &str = "inputs";
+
+/// Every input set: the built-in ones, then the named ones in `inputs/` by
+/// name.
+pub fn sets() -> Vec<String> {
+    let mut named = fs::read_dir(DIR)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !BUILTIN.contains(&name.as_str()))
+        .collect::<Vec<_>>();
+    named.sort();
+    BUILTIN.iter().map(|s| s.to_string()).chain(named).collect()
+}
+
+/// The input file of `day` in `set`, which may not exist.
+pub fn path(set: &str, day: u8) -> PathBuf {
+    match set {
+        "example" => PathBuf::from(format!("day{}/test_case", day)),
+        "real" => PathBuf::from(format!("day{}/input", day)),
+        _ => [DIR, set, &format!("day{}.txt", day)].iter().collect(),
+    }
+}
+
+/// The input of `day` in `set`, `None` when the set has none.
+pub fn load(set: &str, day: u8) -> Option<String> {
+    read_input(&path(set, day)).ok()
+}
+
+/// Whether `set` covers `day`. Named sets often hold a few days only, the
+/// built-in ones are expected to cover every day.
+pub fn covers(set: &str, day: u8) -> bool {
+    BUILTIN.contains(&set) || path(set, day).is_file()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets_list_named_directories() {
+        std::env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).unwrap();
+        let sets = sets();
+        assert_eq!(sets[..2], ["example", "real"]);
+        assert!(sets.contains(&"larger-example".to_string()));
+        assert_eq!(
+            path("larger-example", 22),
+            PathBuf::from("inputs/larger-example/day22.txt")
+        );
+        assert!(covers("larger-example", 22));
+        assert!(!covers("larger-example", 1));
+        assert!(covers("example", 1));
+    }
+}
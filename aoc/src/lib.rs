@@ -0,0 +1,4 @@
+pub mod inputs;
+pub mod registry;
+pub mod timings;
+pub mod verify;
@@ -0,0 +1,180 @@
+use aoc::{inputs, registry, timings, verify};
+use clap::{Parser, Subcommand};
+use common::{load, Format};
+use std::{fmt::Display, path::PathBuf, process};
+use verify::Status;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of one day, or of every day in sequence
+    Run {
+        /// Day to run, every day when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=22))]
+        day: Option<u8>,
+        /// Part to run, both parts when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input, `-` for stdin, defaults to the day's input in `--set`
+        #[arg(long, requires = "day", conflicts_with_all = ["set", "all_sets"])]
+        input: Option<PathBuf>,
+        /// Input set to run against
+        #[arg(long, value_parser = input_set, default_value = "real")]
+        set: String,
+        /// Run against every input set covering the day, one after another
+        #[arg(long, conflicts_with = "set")]
+        all_sets: bool,
+        /// Output format of the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check the solvers against the expected answers of every input set
+    Verify {
+        /// Day to check, every day when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=22))]
+        day: Option<u8>,
+        /// Input set to check, every set when omitted
+        #[arg(long, value_parser = input_set)]
+        set: Option<String>,
+        /// Expected answers by input set, day and part
+        #[arg(long, default_value = "answers.json")]
+        answers: PathBuf,
+    },
+    /// Time every day against one input set, slowest day first
+    Timings {
+        /// Input set to time
+        #[arg(long, value_parser = input_set, default_value = "real")]
+        set: String,
+        /// Runs per day, the table shows the median
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+    },
+    /// Print a random puzzle input of one day
+    Gen {
+        /// Day to generate an input for
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=22))]
+        day: u8,
+        /// Size of the input, its unit depends on the day: lines, boards,
+        /// grid side, scanners...
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the random generator, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+/// Accepts the name of an input set, built-in or found in `inputs/`.
+fn input_set(name: &str) -> Result<String, String> {
+    let sets = inputs::sets();
+    match sets.iter().any(|set| set == name) {
+        true => Ok(name.to_string()),
+        false => Err(format!("no input set {}, expected one of {}", name, sets.join(", "))),
+    }
+}
+
+/// Prints `error` and exits, like the day binaries do.
+fn fail(error: impl Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1)
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            part,
+            input,
+            set,
+            all_sets,
+            format,
+        } => {
+            let days = match day {
+                Some(day) => vec![registry::find(day).unwrap()],
+                None => registry::DAYS.iter().collect(),
+            };
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let sets = match all_sets {
+                true => inputs::sets(),
+                false => vec![set],
+            };
+            for set in &sets {
+                for day in days.iter().filter(|day| inputs::covers(set, day.day)) {
+                    let answers = match &input {
+                        Some(path) => load(path, |input| (day.run)(input, &parts)),
+                        None => match inputs::load(set, day.day) {
+                            Some(input) => (day.run)(&input, &parts).unwrap_or_else(|e| {
+                                let path = inputs::path(set, day.day);
+                                fail(format!("Fail to parse {}: {}", path.display(), e))
+                            }),
+                            None => {
+                                eprintln!("[{}] Day {}: missing input", set, day.day);
+                                continue;
+                            }
+                        },
+                    };
+                    for answer in answers {
+                        match (format, all_sets) {
+                            (Format::Text, false) => println!("{}", answer),
+                            (Format::Text, true) => println!("[{}] {}", set, answer),
+                            (Format::Json, false) => println!("{}", answer.to_json(None)),
+                            (Format::Json, true) => println!("{}", answer.to_json(Some(set))),
+                        }
+                    }
+                }
+            }
+        }
+        Command::Verify { day, set, answers } => {
+            let answers = verify::load(&answers).unwrap_or_else(|e| fail(e));
+            let sets = match set {
+                Some(set) => vec![set],
+                None => inputs::sets(),
+            };
+            let days = match day {
+                Some(day) => vec![registry::find(day).unwrap()],
+                None => registry::DAYS.iter().collect(),
+            };
+            let (mut passed, mut failed, mut missing) = (0, 0, 0);
+            for set in &sets {
+                for day in days.iter().filter(|day| inputs::covers(set, day.day)) {
+                    for check in verify::verify(&answers, set, day) {
+                        println!("{}", check);
+                        match check.status {
+                            Status::Pass => passed += 1,
+                            Status::Fail { .. } => failed += 1,
+                            Status::Missing(_) => missing += 1,
+                        }
+                    }
+                }
+            }
+            println!("{} passed, {} failed, {} missing", passed, failed, missing);
+            if failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Timings { set, runs } => {
+            let mut measured = Vec::new();
+            for day in registry::DAYS.iter() {
+                let Some(input) = inputs::load(&set, day.day) else {
+                    continue;
+                };
+                let timing = timings::measure(day, &input, runs)
+                    .unwrap_or_else(|e| fail(format!("Fail to parse day {}: {}", day.day, e)));
+                measured.push(timing);
+            }
+            print!("{}", timings::table(&measured));
+        }
+        Command::Gen { day, size, seed } => {
+            print!("{}", (registry::find(day).unwrap().generate)(size, seed));
+        }
+    }
+}
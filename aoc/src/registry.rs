@@ -0,0 +1,77 @@
+use common::{solve, Answer, ParseError, Solution};
+
+pub type Runner = fn(&str, &[u8]) -> Result<Vec<Answer>, ParseError>;
+pub type Generator = fn(usize, u64) -> String;
+
+pub struct Day {
+    pub day: u8,
+    pub run: Runner,
+    pub generate: Generator,
+}
+
+const fn day<S: Solution>(generate: Generator) -> Day {
+    Day {
+        day: S::DAY,
+        run: solve::<S>,
+        generate,
+    }
+}
+
+pub const DAYS: [Day; 22] = [
+    day::<day1::Day1>(day1::generator::generate),
+    day::<day2::Day2>(day2::generator::generate),
+    day::<day3::Day3>(day3::generator::generate),
+    day::<day4::Day4>(day4::generator::generate),
+    day::<day5::Day5>(day5::generator::generate),
+    day::<day6::Day6>(day6::generator::generate),
+    day::<day7::Day7>(day7::generator::generate),
+    day::<day8::Day8>(day8::generator::generate),
+    day::<day9::Day9>(day9::generator::generate),
+    day::<day10::Day10>(day10::generator::generate),
+    day::<day11::Day11>(day11::generator::generate),
+    day::<day12::Day12>(day12::generator::generate),
+    day::<day13::Day13>(day13::generator::generate),
+    day::<day14::Day14>(day14::generator::generate),
+    day::<day15::Day15>(day15::generator::generate),
+    day::<day16::Day16>(day16::generator::generate),
+    day::<day17::Day17>(day17::generator::generate),
+    day::<day18::Day18>(day18::generator::generate),
+    day::<day19::Day19>(day19::generator::generate),
+    day::<day20::Day20>(day20::generator::generate),
+    day::<day21::Day21>(day21::generator::generate),
+    day::<day22::Day22>(day22::generator::generate),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Days that solve a generated input of size 300 in well under a second
+    /// in a debug build.
+    const CHEAP_DAYS: [u8; 15] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 14, 16, 21];
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in DAYS.iter() {
+            let large = CHEAP_DAYS.contains(&day.day).then_some((300, 3));
+            for (size, seed) in [(1, 0), (8, 1), (8, 2)].into_iter().chain(large) {
+                let input = (day.generate)(size, seed);
+                if let Err(e) = (day.run)(&input, &[1, 2]) {
+                    panic!("size {}, seed {}: {}", size, seed, e);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generators_follow_the_seed() {
+        for day in DAYS.iter() {
+            assert_eq!((day.generate)(8, 1), (day.generate)(8, 1));
+            assert_ne!((day.generate)(8, 1), (day.generate)(8, 2));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::registry::Day;
+use common::ParseError;
+use std::time::Duration;
+
+/// Durations of one day, each the median over several runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Parses `input` and solves both parts of `day`, `runs` times.
+pub fn measure(day: &Day, input: &str, runs: usize) -> Result<Timing, ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let answers = (day.run)(input, &[1, 2])?;
+        parse.push(answers[0].parse_time);
+        part1.push(answers[0].solve_time);
+        part2.push(answers[1].solve_time);
+    }
+    Ok(Timing {
+        day: day.day,
+        parse: median(parse),
+        part1: median(part1),
+        part2: median(part2),
+    })
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// A markdown table of `timings`, slowest day first.
+pub fn table(timings: &[Timing]) -> String {
+    let mut timings = timings.to_vec();
+    timings.sort_by_key(|t| std::cmp::Reverse(t.total()));
+    let mut out = String::from("| Day | Parse | Part 1 | Part 2 | Total |\n");
+    out.push_str("|----:|------:|-------:|-------:|------:|\n");
+    for t in timings {
+        out.push_str(&format!(
+            "| {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} |\n",
+            t.day,
+            t.parse,
+            t.part1,
+            t.part2,
+            t.total()
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_lists_slowest_first() {
+        let timing = |day, ms| Timing {
+            day,
+            parse: Duration::from_micros(10),
+            part1: Duration::from_millis(ms),
+            part2: Duration::from_millis(1),
+        };
+        let table = table(&[timing(1, 1), timing(19, 900)]);
+        let rows = table.lines().skip(2).collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                "| 19 | 10.00µs | 900.00ms | 1.00ms | 901.01ms |",
+                "| 1 | 10.00µs | 1.00ms | 1.00ms | 2.01ms |",
+            ]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{inputs, registry::Day};
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+/// Expected answers by input set, day and part, as stored in `answers.json`.
+pub type Answers = BTreeMap<String, BTreeMap<u8, BTreeMap<u8, String>>>;
+
+pub fn load(path: &Path) -> Result<Answers, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Fail to load {}: {}", path.display(), e))?;
+    serde_json::from_str(&text).map_err(|e| format!("Fail to parse {}: {}", path.display(), e))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    /// Nothing to compare, either the answer or the input is unknown.
+    Missing(&'static str),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub set: String,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] Day {} part {}: ", self.set, self.day, self.part)?;
+        match &self.status {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL, expected {:?}, got {:?}", expected, actual)
+            }
+            Status::Missing(what) => write!(f, "missing {}", what),
+        }
+    }
+}
+
+/// Runs both parts of `day` against its input of `set` and compares them to
+/// the recorded answers.
+pub fn verify(answers: &Answers, set: &str, day: &Day) -> Vec<Check> {
+    let expected = answers.get(set).and_then(|days| days.get(&day.day));
+    let input = inputs::load(set, day.day);
+    let actual = input.map(|input| (day.run)(&input, &[1, 2]).map_err(|e| e.to_string()));
+    (1..=2)
+        .map(|part| {
+            let expected = expected.and_then(|parts| parts.get(&part));
+            let status = match (expected, &actual) {
+                (None, _) => Status::Missing("answer"),
+                (Some(_), None) => Status::Missing("input"),
+                (Some(expected), Some(actual)) => {
+                    let actual = match actual {
+                        Ok(answers) => answers[part as usize - 1].value.clone(),
+                        Err(e) => e.clone(),
+                    };
+                    if actual.trim_end() == expected.trim_end() {
+                        Status::Pass
+                    } else {
+                        Status::Fail {
+                            expected: expected.clone(),
+                            actual,
+                        }
+                    }
+                }
+            };
+            Check {
+                set: set.to_string(),
+                day: day.day,
+                part,
+                status,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn verify_reports_every_status() {
+        let answers: Answers = serde_json::from_str(
+            r#"{"real": {"21": {"1": "908595", "2": "0"}}, "none": {"21": {"1": "1"}}}"#,
+        )
+        .unwrap();
+        std::env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).unwrap();
+        let day = registry::find(21).unwrap();
+        let statuses = verify(&answers, "real", day)
+            .into_iter()
+            .map(|c| c.status)
+            .collect::<Vec<_>>();
+        assert_eq!(statuses[0], Status::Pass);
+        assert!(matches!(statuses[1], Status::Fail { .. }));
+        let missing = verify(&answers, "none", day);
+        assert_eq!(missing[0].status, Status::Missing("input"));
+        assert_eq!(missing[1].status, Status::Missing("answer"));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{fmt, str::FromStr};
+
+/// A malformed puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, counted in characters, of the offending text.
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "`{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The raw puzzle input of a day. Parsers slice their tokens out of `text`,
+/// so an error can be located from the offending slice alone.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    pub day: u8,
+    pub text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, text: &'a str) -> Self {
+        Source { day, text }
+    }
+
+    /// Error at `fragment`, which should be a slice of `self.text`. Anything
+    /// else is reported at the end of the input.
+    pub fn error(&self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let address = fragment.as_ptr() as usize;
+        let offset = if address >= start && address <= start + self.text.len() {
+            address - start
+        } else {
+            self.text.len()
+        };
+        let before = &self.text[..offset];
+        ParseError {
+            day: self.day,
+            line: before.matches('\n').count() + 1,
+            column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+            text: fragment.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error at the end of `fragment`, for a token missing from it.
+    pub fn missing(&self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&fragment[fragment.len()..], expected)
+    }
+
+    /// Parses the whole of `fragment` with its `FromStr` implementation.
+    pub fn parse<T: FromStr>(
+        &self,
+        fragment: &str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        fragment.parse().map_err(|_| self.error(fragment, expected))
+    }
+
+    /// Parses the next token out of `tokens`, or reports it missing from the
+    /// end of `fragment`.
+    pub fn next<'b, T: FromStr>(
+        &self,
+        tokens: &mut impl Iterator<Item = &'b str>,
+        fragment: &str,
+        expected: impl Into<String>,
+    ) -> Result<T, ParseError> {
+        match tokens.next() {
+            Some(token) => self.parse(token, expected),
+            None => Err(self.missing(fragment, expected)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_fragment() {
+        let input = "1,2\n3,x4\n";
+        let src = Source::new(1, input);
+        let fragment = input.lines().nth(1).unwrap().split(',').nth(1).unwrap();
+        let error = src.parse::<i32>(fragment, "a number").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 3: expected a number, found `x4`"
+        );
+    }
+
+    #[test]
+    fn locate_missing_token() {
+        let input = "1,2\n3";
+        let src = Source::new(1, input);
+        let line = input.lines().nth(1).unwrap();
+        let mut tokens = line.split(',').skip(1);
+        let error = src.next::<i32>(&mut tokens, line, "a number").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 2: expected a number, found end of line"
+        );
+    }
+}
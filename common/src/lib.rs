@@ -0,0 +1,9 @@
+mod error;
+mod input;
+mod run;
+mod solution;
+
+pub use error::{ParseError, Source};
+pub use input::{open_input, read_input};
+pub use run::{load, main, Format};
+pub use solution::{solve, Answer, Solution};
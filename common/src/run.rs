@@ -0,0 +1,51 @@
+use crate::{read_input, solve, ParseError, Solution};
+use clap::{Parser, ValueEnum};
+use std::{
+    path::{Path, PathBuf},
+    process,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Day N part P: answer` lines
+    Text,
+    /// One JSON object per part, with parse and solve durations
+    Json,
+}
+
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Output format of the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// The whole `main` of a day binary: reads the input named on the command
+/// line and prints both answers of `S`.
+pub fn main<S: Solution>() {
+    let args = Args::parse();
+    let answers = load(&args.input, |input| solve::<S>(input, &[1, 2]));
+    for answer in answers {
+        match args.format {
+            Format::Text => println!("{}", answer),
+            Format::Json => println!("{}", answer.to_json(None)),
+        }
+    }
+}
+
+/// Reads the input at `path`, or stdin when `path` is `-`, and hands it to
+/// `parse`. On a missing file or a parse error, prints it and exits, like
+/// every binary of the workspace does.
+pub fn load<T>(path: &Path, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+    let input = read_input(path).unwrap_or_else(|e| {
+        eprintln!("Fail to load {}: {}", path.display(), e);
+        process::exit(1)
+    });
+    parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    })
+}
@@ -0,0 +1,155 @@
+use crate::ParseError;
+use serde::Serialize;
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// A day of the advent, split into parsing the puzzle input once and solving
+/// both parts from the parsed value.
+pub trait Solution {
+    const DAY: u8;
+    /// Parsed puzzle input, allowed to borrow from the raw input text.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub value: String,
+    /// Time spent parsing the input, shared by every part solved from it.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    set: Option<&'a str>,
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+impl Answer {
+    /// A single line JSON object, durations in nanoseconds, naming the input
+    /// `set` when there is one.
+    pub fn to_json(&self, set: Option<&str>) -> String {
+        serde_json::to_string(&JsonAnswer {
+            set,
+            day: self.day,
+            part: self.part,
+            answer: &self.value,
+            parse_ns: self.parse_time.as_nanos() as u64,
+            solve_ns: self.solve_time.as_nanos() as u64,
+        })
+        .unwrap()
+    }
+}
+
+/// Multi-line answers, like day 13's folded paper, start on their own line.
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.contains('\n') {
+            write!(
+                f,
+                "Day {} part {}:\n{}",
+                self.day,
+                self.part,
+                self.value.trim_end()
+            )
+        } else {
+            write!(f, "Day {} part {}: {}", self.day, self.part, self.value)
+        }
+    }
+}
+
+/// Parses `input` once and solves the requested `parts` of `S`, in order.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Answer>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match part {
+                1 => S::part1(&parsed).to_string(),
+                2 => S::part2(&parsed).to_string(),
+                n => panic!("day {} has no part {}", S::DAY, n),
+            };
+            Answer {
+                day: S::DAY,
+                part,
+                value,
+                parse_time,
+                solve_time: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        type Input<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            Ok(input.split(',').collect())
+        }
+        fn part1(input: &Vec<&str>) -> usize {
+            input.len()
+        }
+        fn part2(input: &Vec<&str>) -> String {
+            input.concat()
+        }
+    }
+
+    #[test]
+    fn solve_requested_parts_in_order() {
+        let answers = solve::<Sum>("a,b,c", &[2, 1]).unwrap();
+        assert_eq!(
+            answers
+                .iter()
+                .map(|a| (a.day, a.part, a.value.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(0, 2, "abc"), (0, 1, "3")]
+        );
+        assert_eq!(answers[0].parse_time, answers[1].parse_time);
+    }
+
+    #[test]
+    fn answer_as_json() {
+        let answer = Answer {
+            day: 13,
+            part: 2,
+            value: String::from("#.\n.#"),
+            parse_time: Duration::from_micros(2),
+            solve_time: Duration::from_nanos(30),
+        };
+        assert_eq!(
+            answer.to_json(None),
+            r##"{"day":13,"part":2,"answer":"#.\n.#","parse_ns":2000,"solve_ns":30}"##
+        );
+        assert_eq!(
+            answer.to_json(Some("example")),
+            r##"{"set":"example","day":13,"part":2,"answer":"#.\n.#","parse_ns":2000,"solve_ns":30}"##
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::window_changes;
+
+/// Summary of the depth profile seen through windows of `window` depths,
+/// with moving averages over `average_width` depths. Windows are numbered by
+/// their first depth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub window: usize,
+    pub average_width: usize,
+    /// Number of windows whose sum is larger than the previous one's.
+    pub increases: usize,
+    pub longest_increasing_run: Option<Run>,
+    pub largest_drop: Option<Drop>,
+    /// Mean of every `average_width` consecutive depths.
+    pub moving_averages: Vec<f64>,
+    pub reversals: Vec<Reversal>,
+}
+
+/// Consecutive increases, from window `start` over `length` more windows.
+/// Of equally long runs, the report keeps the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// The sum shrinking by `depth` from window `at` to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Drop {
+    pub at: usize,
+    pub depth: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+}
+
+/// The profile turning to `trend` from window `at` on. Flat stretches keep
+/// the trend they follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reversal {
+    pub at: usize,
+    pub trend: Trend,
+}
+
+/// Analyses `depths` through windows of `window` depths, built on the same
+/// change between neighbouring windows as the puzzle answers, averaging
+/// them over `average_width` depths.
+pub fn analyze(depths: &[i32], window: usize, average_width: usize) -> Report {
+    assert!(window > 0, "a sliding window holds at least one depth");
+    let mut report = Report {
+        window,
+        average_width,
+        increases: 0,
+        longest_increasing_run: None,
+        largest_drop: None,
+        moving_averages: moving_averages(depths, average_width),
+        reversals: Vec::new(),
+    };
+    let mut run: Option<Run> = None;
+    let mut trend = None;
+    for (at, change) in window_changes(depths, window).enumerate() {
+        if change > 0 {
+            report.increases += 1;
+            let current = run.get_or_insert(Run {
+                start: at,
+                length: 0,
+            });
+            current.length += 1;
+            if report
+                .longest_increasing_run
+                .is_none_or(|longest| current.length > longest.length)
+            {
+                report.longest_increasing_run = Some(*current);
+            }
+        } else {
+            run = None;
+        }
+        if change < 0 && report.largest_drop.is_none_or(|drop| -change > drop.depth) {
+            report.largest_drop = Some(Drop { at, depth: -change });
+        }
+        let current = match change {
+            0 => continue,
+            c if c > 0 => Trend::Rising,
+            _ => Trend::Falling,
+        };
+        if trend.is_some_and(|previous| previous != current) {
+            report.reversals.push(Reversal { at, trend: current });
+        }
+        trend = Some(current);
+    }
+    report
+}
+
+/// Mean of every `width` consecutive depths, empty when there are fewer.
+pub fn moving_averages(depths: &[i32], width: usize) -> Vec<f64> {
+    assert!(width > 0, "a moving average spans at least one depth");
+    let Some(first) = depths.get(..width) else {
+        return Vec::new();
+    };
+    let mut sum: i64 = first.iter().map(|&d| i64::from(d)).sum();
+    let mut averages = vec![sum as f64 / width as f64];
+    for change in window_changes(depths, width) {
+        sum += change;
+        averages.push(sum as f64 / width as f64);
+    }
+    averages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn analyze_single_depths() {
+        let report = analyze(&EXAMPLE, 1, 1);
+        assert_eq!(report.increases, 7);
+        assert_eq!(
+            report.longest_increasing_run,
+            Some(Run {
+                start: 0,
+                length: 3
+            })
+        );
+        assert_eq!(report.largest_drop, Some(Drop { at: 3, depth: 10 }));
+        assert_eq!(report.moving_averages.len(), 10);
+        let reversals = report
+            .reversals
+            .iter()
+            .map(|r| (r.at, r.trend))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reversals,
+            vec![
+                (3, Trend::Falling),
+                (4, Trend::Rising),
+                (7, Trend::Falling),
+                (8, Trend::Rising)
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_three_depth_windows() {
+        let report = analyze(&EXAMPLE, 3, 3);
+        assert_eq!(report.increases, crate::puzzle_2(&EXAMPLE));
+        assert_eq!(
+            report.longest_increasing_run,
+            Some(Run {
+                start: 3,
+                length: 4
+            })
+        );
+        assert_eq!(report.largest_drop, Some(Drop { at: 2, depth: 1 }));
+        assert_eq!(report.moving_averages[0], 607.0 / 3.0);
+        assert_eq!(report.moving_averages[7], 792.0 / 3.0);
+        assert_eq!(report.moving_averages.len(), 8);
+        assert_eq!(
+            report.reversals,
+            vec![
+                Reversal {
+                    at: 2,
+                    trend: Trend::Falling
+                },
+                Reversal {
+                    at: 3,
+                    trend: Trend::Rising
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_short_profile() {
+        let report = analyze(&[5, 3], 3, 3);
+        assert_eq!(report.increases, 0);
+        assert_eq!(report.longest_increasing_run, None);
+        assert_eq!(report.largest_drop, None);
+        assert!(report.moving_averages.is_empty());
+        assert!(report.reversals.is_empty());
+    }
+
+    #[test]
+    fn average_apart_from_window() {
+        let report = analyze(&EXAMPLE, 1, 4);
+        assert_eq!(report.increases, 7);
+        assert_eq!(report.average_width, 4);
+        assert_eq!(report.moving_averages.len(), 7);
+        assert_eq!(report.moving_averages[0], 817.0 / 4.0);
+        assert_eq!(report.moving_averages[6], 1032.0 / 4.0);
+        assert_eq!(analyze(&[5, 3], 3, 2).moving_averages, vec![4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a moving average spans at least one depth")]
+    fn reject_empty_average() {
+        analyze(&EXAMPLE, 3, 0);
+    }
+}
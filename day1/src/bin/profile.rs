@@ -0,0 +1,53 @@
+use clap::{builder::RangedU64ValueParser, Parser};
+use common::load;
+use day1::{analysis::analyze, parse};
+use std::path::PathBuf;
+
+/// Reports the runs, drops, trend reversals and moving averages of a depth
+/// profile seen through sliding windows
+#[derive(Parser)]
+struct Args {
+    /// Depth log, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Size of the sliding window the depths are compared through
+    #[arg(
+        long,
+        default_value_t = 3,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    window: usize,
+    /// Depths each moving average spans
+    #[arg(
+        long,
+        default_value_t = 3,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    average_width: usize,
+}
+
+fn main() {
+    let args = Args::parse();
+    let depths = load(&args.input, parse);
+    let report = analyze(&depths, args.window, args.average_width);
+    println!("Window: {}", report.window);
+    println!("Increases: {}", report.increases);
+    match report.longest_increasing_run {
+        Some(run) => println!(
+            "Longest increasing run: {} windows from window {}",
+            run.length, run.start
+        ),
+        None => println!("Longest increasing run: none"),
+    }
+    match report.largest_drop {
+        Some(drop) => println!("Largest drop: {} after window {}", drop.depth, drop.at),
+        None => println!("Largest drop: none"),
+    }
+    for reversal in &report.reversals {
+        println!("Turns {:?} at window {}", reversal.trend, reversal.at);
+    }
+    println!("Moving averages over {} depths:", report.average_width);
+    for average in &report.moving_averages {
+        println!("{:.2}", average);
+    }
+}
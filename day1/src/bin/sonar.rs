@@ -0,0 +1,35 @@
+use clap::{builder::RangedU64ValueParser, Parser};
+use common::open_input;
+use day1::count_increases;
+use std::{path::PathBuf, process};
+
+/// Counts depth increases of a sonar sweep as it is read, in memory bounded
+/// by the largest window, for logs too large to load
+#[derive(Parser)]
+struct Args {
+    /// Depth log, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Size of a sliding window, repeat for several
+    #[arg(
+        long = "window",
+        default_values_t = [1, 3],
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    windows: Vec<usize>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let reader = open_input(&args.input).unwrap_or_else(|e| {
+        eprintln!("Fail to load {}: {}", args.input.display(), e);
+        process::exit(1)
+    });
+    let counts = count_increases(reader, &args.windows).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    });
+    for (window, count) in args.windows.iter().zip(counts) {
+        println!("Window {}: {} increases", window, count);
+    }
+}
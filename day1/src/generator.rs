@@ -0,0 +1,14 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A sonar sweep of `size` depth readings, drifting mostly downwards.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut depth: i32 = rng.gen_range(100..200);
+    (0..size.max(1))
+        .map(|_| {
+            depth = (depth + rng.gen_range(-10..=20)).max(0);
+            depth.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,154 @@
+pub mod analysis;
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Vec<i32>) -> usize {
+        puzzle_1(input)
+    }
+    fn part2(input: &Vec<i32>) -> usize {
+        puzzle_2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let src = Source::new(Day1::DAY, input);
+    input.lines().map(|x| src.parse(x, "a depth")).collect()
+}
+
+pub fn puzzle_1(arr: &[i32]) -> usize {
+    count_window_increases(arr, 1)
+}
+
+pub fn puzzle_2(arr: &[i32]) -> usize {
+    count_window_increases(arr, 3)
+}
+
+/// Counts how often the sum of `window` consecutive depths grows. Like every
+/// windowed count, panics when `window` is 0.
+pub fn count_window_increases(arr: &[i32], window: usize) -> usize {
+    window_changes(arr, window)
+        .filter(|&change| change > 0)
+        .count()
+}
+
+/// How the sum of `window` consecutive depths changes from each window to
+/// the next. Two neighbouring windows share all but their ends, so the
+/// change is the depth entering the window minus the one leaving it.
+pub fn window_changes(arr: &[i32], window: usize) -> impl Iterator<Item = i64> + '_ {
+    assert!(window > 0, "a sliding window holds at least one depth");
+    arr.iter()
+        .zip(arr.get(window..).unwrap_or_default())
+        .map(|(&curr, &next)| i64::from(next) - i64::from(curr))
+}
+
+/// Streaming form of [`count_window_increases`], holding only the last
+/// `window` depths.
+#[derive(Debug, Clone)]
+pub struct Increases {
+    window: usize,
+    recent: VecDeque<i32>,
+    count: usize,
+}
+
+impl Increases {
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "a sliding window holds at least one depth");
+        Increases {
+            window,
+            recent: VecDeque::with_capacity(window + 1),
+            count: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        self.recent.push_back(depth);
+        if self.recent.len() > self.window {
+            let leaving = self.recent.pop_front().unwrap();
+            if depth > leaving {
+                self.count += 1;
+            }
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Reads depths out of `reader` one line at a time and counts increases for
+/// every size in `windows` in a single pass, in memory bounded by the
+/// largest window. A malformed depth is an `InvalidData` error wrapping its
+/// `ParseError`.
+pub fn count_increases(mut reader: impl BufRead, windows: &[usize]) -> io::Result<Vec<usize>> {
+    let mut counters: Vec<Increases> = windows.iter().map(|&w| Increases::new(w)).collect();
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        line_number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        let depth = Source::new(Day1::DAY, text)
+            .parse(text, "a depth")
+            .map_err(|e| {
+                let e = ParseError {
+                    line: line_number,
+                    ..e
+                };
+                io::Error::new(io::ErrorKind::InvalidData, e)
+            })?;
+        counters.iter_mut().for_each(|c| c.push(depth));
+    }
+    Ok(counters.iter().map(Increases::count).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn streaming_matches_slices() {
+        let depths = parse(EXAMPLE).unwrap();
+        assert_eq!((puzzle_1(&depths), puzzle_2(&depths)), (7, 5));
+        let windows = [1, 3, 9, 10, 20];
+        let streamed = count_increases(EXAMPLE.as_bytes(), &windows).unwrap();
+        let sliced = windows.map(|w| count_window_increases(&depths, w));
+        assert_eq!(streamed, sliced);
+        assert_eq!(streamed, [7, 5, 1, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a sliding window holds at least one depth")]
+    fn reject_empty_window() {
+        count_window_increases(&[199, 200], 0);
+    }
+
+    #[test]
+    fn streaming_reports_bad_depth() {
+        let error = count_increases("199\r\n200\r\n2x8\r\n".as_bytes(), &[1]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 3, column 1: expected a depth, found `2x8`"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// `size` navigation lines, each either corrupted or incomplete, with an odd
+/// number of incomplete ones, the first among them, for part 2's middle
+/// score. Chunks nest at most 20 deep, which keeps autocomplete scores within
+/// a `u64`.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut corrupted: Vec<bool> = (0..size.max(1))
+        .map(|i| i > 0 && rng.gen_bool(0.5))
+        .collect();
+    if corrupted.iter().filter(|&&c| !c).count() % 2 == 0 {
+        let last = corrupted.last_mut().unwrap();
+        *last = !*last;
+    }
+    corrupted
+        .into_iter()
+        .map(|corrupted| {
+            let length = rng.gen_range(20..110);
+            let mut corrupt_from = corrupted.then(|| rng.gen_range(0..length));
+            let mut line = String::with_capacity(length + 2);
+            let mut open = Vec::new();
+            for i in 0..length {
+                if corrupt_from.is_some_and(|at| i >= at) && !open.is_empty() {
+                    line.push(wrong_close(&mut rng, open.pop().unwrap()));
+                    corrupt_from = None;
+                } else if !open.is_empty() && (open.len() >= 20 || rng.gen_bool(0.4)) {
+                    line.push(open.pop().unwrap());
+                } else {
+                    let (opening, close) = PAIRS[rng.gen_range(0..PAIRS.len())];
+                    line.push(opening);
+                    open.push(close);
+                }
+            }
+            if corrupt_from.is_some() {
+                let (opening, close) = PAIRS[rng.gen_range(0..PAIRS.len())];
+                line.push(opening);
+                line.push(wrong_close(&mut rng, close));
+            } else if open.is_empty() {
+                let (opening, _) = PAIRS[rng.gen_range(0..PAIRS.len())];
+                line.push(opening);
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn wrong_close(rng: &mut StdRng, expected: char) -> char {
+    loop {
+        let (_, close) = PAIRS[rng.gen_range(0..PAIRS.len())];
+        if close != expected {
+            break close;
+        }
+    }
+}
@@ -0,0 +1,167 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use itertools::Itertools;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        let src = Source::new(Self::DAY, input);
+        let lines = input
+            .lines()
+            .map(|l| match l.char_indices().find(|(_, c)| !"([{<>}])".contains(*c)) {
+                Some((i, c)) => Err(src.error(&l[i..i + c.len_utf8()], "a bracket")),
+                None => Ok(l),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if lines.is_empty() {
+            return Err(src.missing(input, "a line of brackets"));
+        }
+        Ok(lines)
+    }
+    fn part1(lines: &Vec<&str>) -> usize {
+        lines
+            .iter()
+            .map(|l| match l.find_invalid_character() {
+                Some(c) => c.score_p1(),
+                None => 0,
+            })
+            .sum()
+    }
+    fn part2(lines: &Vec<&str>) -> usize {
+        let scores = lines
+            .iter()
+            .filter_map(|l| l.complete())
+            .map(|o| o.iter().fold(0usize, |acc, c| acc * 5 + c.score_p2()))
+            .sorted()
+            .collect_vec();
+        scores[scores.len() / 2]
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day10::part1(&Day10::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    Day10::part2(&Day10::parse(input).unwrap())
+}
+
+trait LineExt {
+    fn find_invalid_character(self) -> Option<char>;
+    fn complete(self) -> Option<Vec<char>>;
+}
+
+trait CharExt {
+    fn is_open(&self) -> bool;
+    fn score_p1(self) -> usize;
+    fn score_p2(self) -> usize;
+    fn closing_pair(self) -> char;
+    fn opening_pair(self) -> char;
+}
+
+impl LineExt for &str {
+    fn find_invalid_character(self) -> Option<char> {
+        let mut stack = Vec::<char>::new();
+        for c in self.chars() {
+            if c.is_open() {
+                stack.push(c)
+            } else {
+                if stack.last() == Some(&c.opening_pair()) {
+                    stack.pop();
+                } else {
+                    return Some(c);
+                }
+            }
+        }
+        None
+    }
+    fn complete(self) -> Option<Vec<char>> {
+        let mut stack = Vec::<char>::new();
+        for c in self.chars() {
+            if c.is_open() {
+                stack.push(c)
+            } else {
+                if stack.last() == Some(&c.opening_pair()) {
+                    stack.pop();
+                } else {
+                    // Drop corrupted lines
+                    return None;
+                }
+            }
+        }
+        Some(stack.iter().map(|c| c.closing_pair()).rev().collect_vec())
+    }
+}
+
+impl CharExt for char {
+    fn closing_pair(self) -> char {
+        match self {
+            '{' => '}',
+            '(' => ')',
+            '[' => ']',
+            '<' => '>',
+            v => panic!("Unexpected char {}  ", v),
+        }
+    }
+    fn opening_pair(self) -> char {
+        match self {
+            '}' => '{',
+            ')' => '(',
+            ']' => '[',
+            '>' => '<',
+            v => panic!("Unexpected char {}  ", v),
+        }
+    }
+    fn is_open(&self) -> bool {
+        matches!(*self, '{' | '(' | '[' | '<')
+    }
+    fn score_p1(self) -> usize {
+        match self {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            c => panic!("try to get p1 score for invalid char {}", c),
+        }
+    }
+    fn score_p2(self) -> usize {
+        match self {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            c => panic!("try to get p2 score for invalid char {}", c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 26397);
+    }
+
+    #[test]
+    fn parse_reports_foreign_character() {
+        let error = Day10::parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]a({[<{<<[]>>(\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 11, "a"));
+        assert_eq!(Day10::parse("").unwrap_err().expected, "a line of brackets");
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 288957);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Day11, Puzzle};
+use common::{Solution, Source};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Largest grid drawn fully at random, the size of the puzzle's own. Larger
+/// random grids practically never synchronize.
+const RANDOM_SIZE: usize = 10;
+const ATTEMPTS: usize = 100;
+const STEPS: usize = 1000;
+
+/// A `size` by `size` grid of octopus energy levels that synchronizes, so
+/// part 2 has an answer. Small grids are drawn at random until one
+/// synchronizes within 1000 steps. Otherwise every octopus starts at one of
+/// two neighbouring levels: those at the higher level flash first, and their
+/// flashes carry over to the others, so the whole grid flashes at once
+/// within 10 steps.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    if size <= RANDOM_SIZE {
+        for _ in 0..ATTEMPTS {
+            let grid = draw(size, || rng.gen_range(0..=9));
+            let mut puzzle = Puzzle::parse(Source::new(Day11::DAY, &grid)).unwrap();
+            for _ in 0..STEPS {
+                if puzzle.is_synchronized() {
+                    return grid;
+                }
+                puzzle.step();
+            }
+        }
+    }
+    let low = rng.gen_range(0..=8);
+    draw(size, || low + u8::from(rng.gen_bool(0.5)))
+}
+
+fn draw(size: usize, mut level: impl FnMut() -> u8) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + level()))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
@@ -0,0 +1,58 @@
+pub mod generator;
+mod puzzle;
+use common::{ParseError, Solution, Source};
+pub use puzzle::Puzzle;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = Puzzle;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(Source::new(Self::DAY, input))
+    }
+    fn part1(puzzle: &Puzzle) -> u64 {
+        let mut puzzle = puzzle.clone();
+        (0..100).for_each(|_| {
+            puzzle.step();
+        });
+        puzzle.flash_count
+    }
+    fn part2(puzzle: &Puzzle) -> u64 {
+        let mut puzzle = puzzle.clone();
+        loop {
+            if puzzle.is_synchronized() {
+                return puzzle.iteration;
+            }
+            puzzle.step();
+        }
+    }
+}
+
+pub fn puzzle_1(input: &str) -> u64 {
+    Day11::part1(&Day11::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> u64 {
+    Day11::part2(&Day11::parse(input).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 1656)
+    }
+    #[test]
+    fn puzzle_2_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 195)
+    }
+}
@@ -1,126 +1,56 @@
-use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
+use common::{ParseError, Source};
+use grid::{DenseGrid, Point};
 
-type Pos = i8;
-
-type Position = (i8, i8);
-
-type Cavern = HashMap<Position, Octopus>;
-
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Puzzle {
-    pending_charges: HashSet<Position>,
-    pending_flashes: HashSet<Position>,
-    cavern: Cavern,
+    cavern: DenseGrid<u8>,
     pub flash_count: u64,
     pub iteration: u64,
 }
 
 impl Puzzle {
-    pub fn from(input: &str) -> Self {
-        let mut cavern = Cavern::new();
-        input.trim().lines().enumerate().for_each(|(y, l)| {
-            l.chars().enumerate().for_each(|(x, c)| {
-                cavern.insert(
-                    (x as i8, y as i8),
-                    Octopus {
-                        pos: (x as i8, y as i8),
-                        energy_level: c.to_digit(10).unwrap() as u8,
-                    },
-                );
-            });
-        });
-        Puzzle {
-            pending_charges: HashSet::new(),
-            pending_flashes: HashSet::new(),
+    pub fn parse(src: Source) -> Result<Self, ParseError> {
+        Ok(Puzzle {
+            cavern: DenseGrid::parse(src, src.text.trim())?,
             flash_count: 0,
-            cavern,
             iteration: 0,
-        }
+        })
     }
 
     pub fn is_synchronized(&self) -> bool {
-        !self
-            .cavern
-            .iter()
-            .map(|(_, o)| o.energy_level)
-            .any(|i| i != 0)
+        self.cavern.values().all(|&energy_level| energy_level == 0)
     }
 
     pub fn step(&mut self) {
-        self.cavern.iter_mut().for_each(|(&pos, o)| {
-            o.energy_level += 1;
-            if o.energy_level > 9 {
-                self.pending_flashes.insert(pos);
-            }
-        });
-        self.process_flash();
+        self.cavern
+            .values_mut()
+            .for_each(|energy_level| *energy_level += 1);
+        let mut pending_flashes: Vec<Point> = self
+            .cavern
+            .iter()
+            .filter(|(_, &energy_level)| energy_level > 9)
+            .map(|(pos, _)| pos)
+            .collect();
+        while let Some(pos) = pending_flashes.pop() {
+            self.flash_at(pos, &mut pending_flashes);
+        }
         self.iteration += 1;
     }
 
-    fn flash_at(&mut self, pos: Position) {
-        let mut positions: Option<Vec<Position>> = None;
-        if let Some(o) = self.cavern.get_mut(&pos) {
-            self.pending_flashes.remove(&pos);
-            if o.flashing() {
-                return;
-            }
-            positions = Some(o.neighbors());
-            o.energy_level = 0;
-            self.flash_count += 1;
-        }
-        if let Some(poses) = positions {
-            poses.iter().for_each(|&pos| {
-                self.flash_charge(pos);
-            })
+    fn flash_at(&mut self, pos: Point, pending_flashes: &mut Vec<Point>) {
+        if self.cavern[pos] == 0 {
+            return;
         }
-    }
-
-    fn flash_charge(&mut self, pos: Position) {
-        if let Some(o) = self.cavern.get_mut(&pos) {
-            if o.energy_level != 0 {
-                o.energy_level += 1;
-                if o.energy_level > 9 {
-                    self.pending_flashes.insert(pos);
+        self.cavern[pos] = 0;
+        self.flash_count += 1;
+        for neighbor in self.cavern.neighbors8(pos).collect::<Vec<_>>() {
+            let energy_level = &mut self.cavern[neighbor];
+            if *energy_level != 0 {
+                *energy_level += 1;
+                if *energy_level > 9 {
+                    pending_flashes.push(neighbor);
                 }
             }
         }
     }
-
-    fn process_flash(&mut self) {
-        while self.pending_flashes.len() > 0 {
-            self.pending_flashes
-                .clone()
-                .iter()
-                .for_each(|&pos| self.flash_at(pos));
-        }
-    }
 }
-
-#[derive(Debug)]
-struct Octopus {
-    pos: Position,
-    energy_level: u8,
-}
-
-impl Octopus {
-    fn flashing(&self) -> bool {
-        self.energy_level == 0
-    }
-
-    fn neighbors(&self) -> Vec<(Pos, Pos)> {
-        [
-            (-1, -1),
-            (0, -1),
-            (1, -1),
-            (-1, 0),
-            (1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-        ]
-        .iter()
-        .map(|(x, y)| (self.pos.0 + x, self.pos.1 + y))
-        .collect_vec()
-    }
-}
\ No newline at end of file
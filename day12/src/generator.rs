@@ -0,0 +1,55 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+/// A cave system of `size` small caves and a third as many big ones. Big
+/// caves only connect to small ones, so the number of paths stays finite.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut names = (0..).map(name).filter(|n| n != "end");
+    let small: Vec<String> = names.by_ref().take(size.max(1)).collect();
+    let big: Vec<String> = (0..size / 3 + 1).map(|i| name(i).to_uppercase()).collect();
+    let caves: Vec<&String> = small.iter().chain(&big).collect();
+    let mut connections = BTreeSet::new();
+    let mut connect = |a: &str, b: &str| {
+        if a != b {
+            connections.insert((a.min(b).to_string(), a.max(b).to_string()));
+        }
+    };
+    for cave in &small {
+        connect(cave, caves.choose(&mut rng).unwrap());
+    }
+    for cave in &big {
+        for _ in 0..2 {
+            connect(cave, small.choose(&mut rng).unwrap());
+        }
+    }
+    for end in ["start", "end"] {
+        for _ in 0..2 {
+            connect(end, caves.choose(&mut rng).unwrap());
+        }
+    }
+    let mut lines = connections
+        .into_iter()
+        .map(|(a, b)| {
+            if rng.gen() {
+                format!("{}-{}", a, b)
+            } else {
+                format!("{}-{}", b, a)
+            }
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(&mut rng);
+    lines.join("\n")
+}
+
+/// The `i`th two or more letter cave name: `aa`, `ab`, ... `zz`, `baa`, ...
+fn name(i: usize) -> String {
+    let mut name = Vec::new();
+    let mut i = i;
+    while name.len() < 2 || i > 0 {
+        name.push(b'a' + (i % 26) as u8);
+        i /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
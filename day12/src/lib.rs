@@ -0,0 +1,119 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input<'a> = Puzzle<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Puzzle<'_>, ParseError> {
+        Puzzle::parse(Source::new(Self::DAY, input))
+    }
+    fn part1(puzzle: &Puzzle) -> usize {
+        let mut puzzle = puzzle.clone();
+        puzzle.count_routes("start", false);
+        puzzle.route_count
+    }
+    fn part2(puzzle: &Puzzle) -> usize {
+        let mut puzzle = puzzle.clone();
+        puzzle.count_routes("start", true);
+        puzzle.route_count
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day12::part1(&Day12::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    Day12::part2(&Day12::parse(input).unwrap())
+}
+
+fn is_lower_case(input: &str) -> bool {
+    lazy_static! {
+        static ref LOWER_CASE: Regex = Regex::new("^[a-z]+$").unwrap();
+    }
+    LOWER_CASE.is_match(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct Puzzle<'a> {
+    walk_stack: Vec<&'a str>,
+    route_count: usize,
+    adjacent: HashMap<&'a str, HashSet<&'a str>>,
+}
+
+impl<'a> Puzzle<'a> {
+    fn parse(src: Source<'a>) -> Result<Self, ParseError> {
+        let mut puzzle = Puzzle {
+            route_count: 0,
+            adjacent: HashMap::new(),
+            walk_stack: Vec::new(),
+        };
+        for l in src.text.lines() {
+            let (a, b) = l
+                .split_once('-')
+                .ok_or_else(|| src.error(l, "a connection `a-b`"))?;
+            let adjencent_a = puzzle.adjacent.entry(a).or_default();
+            adjencent_a.insert(b);
+            let adjencent_b = puzzle.adjacent.entry(b).or_default();
+            adjencent_b.insert(a);
+        }
+        if !puzzle.adjacent.contains_key("start") {
+            return Err(src.missing(src.text, "a connection to the `start` cave"));
+        }
+        Ok(puzzle)
+    }
+    fn count_routes(&mut self, node: &'a str, second_chance: bool) {
+        let mut give_second_chance = second_chance;
+        if is_lower_case(node) && self.walk_stack.contains(&node) {
+            if give_second_chance && node != "start" {
+                give_second_chance = false
+            } else {
+                return;
+            }
+        }
+        if node == "end" {
+            self.route_count += 1;
+            return;
+        }
+        self.walk_stack.push(node);
+        let adjencent = self.adjacent.get(node).unwrap().clone();
+        for next_node in adjencent {
+            self.count_routes(next_node, give_second_chance)
+        }
+        self.walk_stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 226);
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 3509);
+    }
+
+    #[test]
+    fn parse_reports_missing_start() {
+        let error = Day12::parse("a-end\nA-a").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.expected, "a connection to the `start` cave");
+    }
+}
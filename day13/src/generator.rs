@@ -0,0 +1,48 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+/// Folds of the puzzle's 1311 by 895 paper, interleaved like the real
+/// instructions, down to a 40 by 6 code.
+const FOLDS: [(char, i64); 12] = [
+    ('x', 655),
+    ('y', 447),
+    ('x', 327),
+    ('y', 223),
+    ('x', 163),
+    ('y', 111),
+    ('x', 81),
+    ('y', 55),
+    ('x', 40),
+    ('y', 27),
+    ('y', 13),
+    ('y', 6),
+];
+
+/// `size` dots and the folds revealing them. Dots are placed in the folded
+/// code and unfolded at random, so none lands on a fold line.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut dots = BTreeSet::new();
+    // The folded paper holds 240 cells, each unfolding into 4096 dots.
+    while dots.len() < size.clamp(1, 240 * 4096) {
+        let mut dot = (rng.gen_range(0..40), rng.gen_range(0..6));
+        for &(axis, at) in FOLDS.iter().rev() {
+            if rng.gen() {
+                match axis {
+                    'x' => dot.0 = 2 * at - dot.0,
+                    _ => dot.1 = 2 * at - dot.1,
+                }
+            }
+        }
+        dots.insert(dot);
+    }
+    let dots = dots
+        .into_iter()
+        .map(|(x, y)| format!("{},{}", x, y))
+        .collect::<Vec<_>>();
+    let folds = FOLDS
+        .iter()
+        .map(|(axis, at)| format!("fold along {}={}", axis, at))
+        .collect::<Vec<_>>();
+    format!("{}\n\n{}", dots.join("\n"), folds.join("\n"))
+}
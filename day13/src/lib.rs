@@ -0,0 +1,148 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use grid::{Point, SparseGrid};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(Source::new(Self::DAY, input))
+    }
+    fn part1(puzzle: &Puzzle) -> usize {
+        let mut puzzle = puzzle.clone();
+        let instruction = puzzle.instructions[0];
+        puzzle.process_instruction(&instruction);
+        puzzle.points.len()
+    }
+    fn part2(puzzle: &Puzzle) -> String {
+        let mut puzzle = puzzle.clone();
+        puzzle
+            .instructions
+            .clone()
+            .iter()
+            .for_each(|i| puzzle.process_instruction(i));
+        puzzle.to_string()
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day13::part1(&Day13::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> String {
+    Day13::part2(&Day13::parse(input).unwrap())
+}
+
+#[derive(Clone)]
+pub struct Puzzle {
+    points: SparseGrid<bool>,
+    instructions: Vec<Instruction>,
+}
+
+#[derive(Clone, Copy)]
+enum Instruction {
+    FoldX(i64),
+    FoldY(i64),
+}
+
+impl Puzzle {
+    fn parse(src: Source) -> Result<Self, ParseError> {
+        let (points, instructions) = src
+            .text
+            .split_once("\n\n")
+            .ok_or_else(|| src.missing(src.text, "fold instructions"))?;
+        let points = points
+            .lines()
+            .map(|l| Ok((parse_point(src, l)?, true)))
+            .collect::<Result<_, _>>()?;
+        let instructions: Vec<_> = instructions
+            .lines()
+            .map(|l| Instruction::parse(src, l))
+            .collect::<Result<_, _>>()?;
+        if instructions.is_empty() {
+            return Err(src.missing(src.text, "fold instructions"));
+        }
+        Ok(Puzzle {
+            points,
+            instructions,
+        })
+    }
+    fn process_instruction(&mut self, instruction: &Instruction) {
+        let fold = |p: Point| match *instruction {
+            Instruction::FoldX(x) if p.x > x => Point::new(2 * x - p.x, p.y),
+            Instruction::FoldY(y) if p.y > y => Point::new(p.x, 2 * y - p.y),
+            _ => p,
+        };
+        self.points = self
+            .points
+            .points()
+            .map(fold)
+            .filter(|p| p.x >= 0 && p.y >= 0)
+            .map(|p| (p, true))
+            .collect();
+    }
+}
+
+impl std::fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some((_, max)) = self.points.bounds() {
+            let bounds = (Point::new(0, 0), max);
+            let text = self.points.render(bounds, |p| if p.is_some() { '#' } else { ' ' });
+            write!(f, "{}", text)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_point(src: Source, s: &str) -> Result<Point, ParseError> {
+    let mut iter = s.split(',');
+    let x = src.next(&mut iter, s, "an x coordinate")?;
+    let y = src.next(&mut iter, s, "a y coordinate")?;
+    Ok(Point::new(x, y))
+}
+
+impl Instruction {
+    fn parse(src: Source, s: &str) -> Result<Instruction, ParseError> {
+        lazy_static! {
+            static ref PATTERN: Regex = Regex::new(r"along (x|y)=(\d+)$").unwrap();
+        }
+        let captures = PATTERN
+            .captures(s)
+            .ok_or_else(|| src.error(s, "a fold `fold along x=N`"))?;
+        let axis = captures.get(1).unwrap().as_str();
+        let pos = captures.get(2).unwrap().as_str();
+        match (axis, src.parse(pos, "a fold position")?) {
+            ("x", p) => Ok(Instruction::FoldX(p)),
+            (_, p) => Ok(Instruction::FoldY(p)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 17);
+    }
+
+    #[test]
+    fn parse_reports_bad_fold() {
+        let input = "6,10\n0,14\n\nfold along y=7\nfold along z=5\n";
+        let error = Day13::parse(input).err().unwrap();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.expected, "a fold `fold along x=N`");
+        let error = Day13::parse("6,10\n0,14\n\n").err().unwrap();
+        assert_eq!(error.expected, "fold instructions");
+    }
+}
@@ -1,17 +1,15 @@
 use std::fs;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-#[path="../src/main.rs"]
-mod main;
 
 fn aggregated_counter(input: &str) -> usize {
-    let mut puzzle = main::Puzzle::from_str(input);
+    let mut puzzle = day14::Puzzle::parse(input).unwrap();
     puzzle.step(40);
     puzzle.score()
 }
 
 fn memorized_counter(input: &str) -> usize {
-    let mut puzzle = main::puzzle_dp::Puzzle::from_str(input);
+    let mut puzzle = day14::puzzle_dp::Puzzle::parse(input).unwrap();
     puzzle.score_depth(40)
 }
 
@@ -0,0 +1,17 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+const ELEMENTS: &[u8] = b"BCFHKNOPSV";
+
+/// A polymer template of `size` elements, with an insertion rule for every
+/// pair of elements.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut element = || char::from(*ELEMENTS.choose(&mut rng).unwrap());
+    let template = (0..size.max(2)).map(|_| element()).collect::<String>();
+    let rules = ELEMENTS
+        .iter()
+        .flat_map(|&a| ELEMENTS.iter().map(move |&b| (a, b)))
+        .map(|(a, b)| format!("{}{} -> {}", char::from(a), char::from(b), element()))
+        .collect::<Vec<_>>();
+    format!("{}\n\n{}", template, rules.join("\n"))
+}
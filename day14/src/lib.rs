@@ -0,0 +1,144 @@
+use common::{ParseError, Solution, Source};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+pub mod generator;
+pub mod puzzle_dp;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input<'a> = Puzzle<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Puzzle<'_>, ParseError> {
+        Puzzle::parse(input)
+    }
+    fn part1(puzzle: &Puzzle) -> usize {
+        let mut puzzle = puzzle.clone();
+        puzzle.step(10);
+        puzzle.score()
+    }
+    fn part2(puzzle: &Puzzle) -> usize {
+        let mut puzzle = puzzle.clone();
+        puzzle.step(40);
+        puzzle.score()
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day14::part1(&Day14::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    Day14::part2(&Day14::parse(input).unwrap())
+}
+
+/// Splits the input into the polymer template and its pair insertion rules.
+fn parse_template(input: &str) -> Result<(&str, BTreeMap<&str, char>), ParseError> {
+    let src = Source::new(Day14::DAY, input);
+    let (init, rules) = input
+        .trim()
+        .split_once("\n\n")
+        .ok_or_else(|| src.missing(input, "pair insertion rules"))?;
+    let instructions = rules
+        .lines()
+        .map(|l| {
+            let (pattern, insert) = l
+                .split_once(" -> ")
+                .ok_or_else(|| src.error(l, "a rule `AB -> C`"))?;
+            let mut chars = insert.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok((pattern, c)),
+                _ => Err(src.error(insert, "a single element")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((init, instructions))
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct Puzzle<'a> {
+    init: &'a str,
+    instructions: BTreeMap<&'a str, char>,
+    // computational state, alternative to solving by DP memoized fib like solution
+    // we can count pairs like counting fishes at day 6
+    pairs_count: BTreeMap<String, usize>,
+    chars_count: BTreeMap<char, usize>,
+}
+
+impl<'a> Puzzle<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let (init, instructions) = parse_template(input)?;
+        let mut puzzle = Puzzle {
+            init,
+            instructions,
+            pairs_count: BTreeMap::new(),
+            chars_count: BTreeMap::new(),
+        };
+        init.chars().for_each(|c| {
+            let char_count = puzzle.chars_count.entry(c).or_insert(0);
+            *char_count += 1
+        });
+        init.chars()
+            .collect_vec()
+            .as_slice()
+            .windows(2)
+            .for_each(|chars| {
+                let key = chars.iter().join("");
+                let pair_count = puzzle.pairs_count.entry(key).or_insert(0);
+                *pair_count += 1;
+            });
+        Ok(puzzle)
+    }
+    pub fn step(&mut self, depth: usize) {
+        for _ in 0..depth {
+            let p_counts = self.pairs_count.clone();
+            for (pair, count) in &p_counts {
+                if let Some(&ch) = self.instructions.get(pair.as_str()) {
+                    let mut it = pair.chars();
+                    let key_1 = format!("{}{}", it.next().unwrap(), ch);
+                    let key_2 = format!("{}{}", ch, it.next().unwrap());
+                    let count_left = self.pairs_count.entry(key_1).or_insert(0);
+                    *count_left += count;
+                    let count_right = self.pairs_count.entry(key_2).or_insert(0);
+                    *count_right += count;
+
+                    let counter = self.chars_count.entry(ch).or_insert(0);
+                    *counter += count;
+                    let existing_pair_counter =
+                        self.pairs_count.entry(pair.clone()).or_insert(*count);
+                    *existing_pair_counter -= count;
+                }
+            }
+        }
+    }
+
+    pub fn score(&self) -> usize {
+        let sorted = self
+            .chars_count
+            .iter()
+            .sorted_by(|a, b| Ord::cmp(a.1, b.1))
+            .collect_vec();
+        sorted.last().unwrap().1 - sorted.first().unwrap().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 1588);
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 2188189693529);
+    }
+}
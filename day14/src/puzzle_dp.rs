@@ -1,3 +1,5 @@
+use crate::parse_template;
+use common::ParseError;
 use itertools::Itertools;
 use std::collections::BTreeMap;
 
@@ -18,21 +20,13 @@ pub struct Puzzle<'a> {
 }
 
 impl<'a> Puzzle<'a> {
-    pub fn from_str(input: &'a str) -> Self {
-        let mut iter = input.trim().split("\n\n");
-        let init = iter.next().unwrap();
-        let mut puzzle = Puzzle {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let (init, instructions) = parse_template(input)?;
+        Ok(Puzzle {
             init,
-            instructions: BTreeMap::new(),
+            instructions,
             cache: BTreeMap::new(),
-        };
-        iter.next().unwrap().lines().for_each(|l| {
-            let mut iter = l.split(" -> ");
-            let pattern = iter.next().unwrap();
-            let insert = iter.next().unwrap().chars().next().unwrap();
-            puzzle.instructions.insert(pattern, insert);
-        });
-        puzzle
+        })
     }
     fn char_counter_depth(&mut self, pair: &str, depth: usize) -> Option<BTreeMap<char, usize>> {
         let key = (String::from(pair), depth);
@@ -49,10 +43,10 @@ impl<'a> Puzzle<'a> {
                 let mut tree = combine_map(
                     &self
                         .char_counter_depth(&key_left, depth - 1)
-                        .unwrap_or(BTreeMap::new()),
+                        .unwrap_or_default(),
                     &self
                         .char_counter_depth(&key_right, depth - 1)
-                        .unwrap_or(BTreeMap::new()),
+                        .unwrap_or_default(),
                 );
                 let char_counter = tree.entry(*c).or_insert(0);
                 *char_counter += 1;
@@ -74,7 +68,6 @@ impl<'a> Puzzle<'a> {
 
         let char_counters = self
             .init
-            .clone()
             .chars()
             .collect_vec()
             .as_slice()
@@ -103,21 +96,21 @@ mod tests {
     #[test]
     fn puzzle_1_working() {
         let input = fs::read_to_string("test_case").unwrap();
-        let mut puzzle = Puzzle::from_str(&input);
+        let mut puzzle = Puzzle::parse(&input).unwrap();
         assert_eq!(puzzle.score_depth(10), 1588);
     }
 
     #[test]
     fn puzzle_2_working() {
         let input = fs::read_to_string("test_case").unwrap();
-        let mut puzzle = Puzzle::from_str(&input);
+        let mut puzzle = Puzzle::parse(&input).unwrap();
         assert_eq!(puzzle.score_depth(40), 2188189693529);
     }
 
     #[test]
     fn puzzle_2_bench() {
         let input = fs::read_to_string("input").unwrap();
-        let mut puzzle = Puzzle::from_str(&input);
+        let mut puzzle = Puzzle::parse(&input).unwrap();
         assert_eq!(puzzle.score_depth(40), 3459822539451);
     }
 }
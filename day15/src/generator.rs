@@ -0,0 +1,14 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A `size` by `size` grid of risk levels between 1 and 9.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| char::from(b'0' + rng.gen_range(1..=9)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,109 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use grid::{DenseGrid, Point};
+use pathfinding::prelude::astar;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = Puzzle;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(Source::new(Self::DAY, input))
+    }
+    fn part1(puzzle: &Puzzle) -> i64 {
+        puzzle.risk_score()
+    }
+    fn part2(puzzle: &Puzzle) -> i64 {
+        let mut puzzle = puzzle.clone();
+        puzzle.expand();
+        puzzle.risk_score()
+    }
+}
+
+pub fn puzzle_1(input: &str) -> i64 {
+    Day15::part1(&Day15::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> i64 {
+    Day15::part2(&Day15::parse(input).unwrap())
+}
+
+#[derive(Clone)]
+pub struct Puzzle {
+    board: DenseGrid<u8>,
+}
+
+impl Puzzle {
+    fn parse(src: Source) -> Result<Self, ParseError> {
+        let board = DenseGrid::parse(src, src.text.trim())?;
+        if board.width() == 0 {
+            return Err(src.missing(src.text, "a row of risk levels"));
+        }
+        Ok(Self { board })
+    }
+    fn goal(&self) -> Point {
+        Point::new(self.board.width() as i64 - 1, self.board.height() as i64 - 1)
+    }
+    fn risk_score(&self) -> i64 {
+        let goal = self.goal();
+        astar(
+            &Point::new(0, 0),
+            |&p| {
+                self.board
+                    .neighbors4(p)
+                    .map(|n| (n, self.board[n] as i64))
+                    .collect::<Vec<_>>()
+            },
+            |&p| p.manhattan(goal) as i64,
+            |&p| p == goal,
+        )
+        .unwrap()
+        .1
+    }
+
+    fn expand(&mut self) {
+        let (width, height) = (self.board.width(), self.board.height());
+        let board = &self.board;
+        self.board = DenseGrid::from_fn(width * 5, height * 5, |p| {
+            let (w, h) = (width as i64, height as i64);
+            let tile = (p.x / w + p.y / h) as u8;
+            (board[Point::new(p.x % w, p.y % h)] + tile - 1) % 9 + 1
+        });
+    }
+}
+
+impl std::fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 40)
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 315)
+    }
+
+    #[test]
+    fn parse_reports_empty_cavern() {
+        let error = Day15::parse("\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of risk levels");
+    }
+}
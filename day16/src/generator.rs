@@ -0,0 +1,81 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A BITS transmission of about `size` packets. Products only take 2 or 3
+/// small literals, so every expression fits in a `u64`, and only stand in
+/// for a handful of packets.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut bits = Vec::new();
+    packet(&mut rng, size.max(1), &mut bits);
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+    bits.chunks(4)
+        .map(|nibble| {
+            let n = nibble.iter().fold(0, |acc, &b| acc * 2 + b as u32);
+            char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+fn push(bits: &mut Vec<bool>, value: u64, width: u32) {
+    bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+}
+
+/// Appends a packet spending `budget` packets, itself included.
+fn packet(rng: &mut StdRng, budget: usize, bits: &mut Vec<bool>) {
+    push(bits, rng.gen_range(0..8), 3);
+    if budget == 1 {
+        let groups = rng.gen_range(1..=3);
+        return literal(rng, groups, bits);
+    }
+    let r#type = match budget {
+        2 => [0, 2, 3][rng.gen_range(0..3)],
+        3 | 4 => [0, 1, 2, 3, 5, 6, 7][rng.gen_range(0..7)],
+        _ => [0, 2, 3, 5, 6, 7][rng.gen_range(0..6)],
+    };
+    push(bits, r#type, 3);
+    let mut children = Vec::new();
+    if r#type == 1 {
+        for _ in 0..rng.gen_range(2..=3) {
+            let mut child = Vec::new();
+            push(&mut child, rng.gen_range(0..8), 3);
+            literal(rng, 2, &mut child);
+            children.push(child);
+        }
+    } else {
+        let count = match r#type {
+            5..=7 => 2,
+            _ => rng.gen_range(1..=(budget - 1).min(8)),
+        };
+        let mut left = budget - 1;
+        for i in 0..count {
+            let share = match count - i {
+                1 => left,
+                rest => rng.gen_range(1..=left - (rest - 1)),
+            };
+            left -= share;
+            let mut child = Vec::new();
+            packet(rng, share, &mut child);
+            children.push(child);
+        }
+    }
+    let length: usize = children.iter().map(Vec::len).sum();
+    if length < 1 << 15 && rng.gen() {
+        bits.push(false);
+        push(bits, length as u64, 15);
+    } else {
+        bits.push(true);
+        push(bits, children.len() as u64, 11);
+    }
+    children.into_iter().for_each(|child| bits.extend(child));
+}
+
+/// Appends the type and value of a literal packet of `groups` digits.
+fn literal(rng: &mut StdRng, groups: usize, bits: &mut Vec<bool>) {
+    push(bits, 4, 3);
+    for i in 0..groups {
+        bits.push(i + 1 < groups);
+        push(bits, rng.gen_range(0..16), 4);
+    }
+}
@@ -0,0 +1,299 @@
+pub mod generator;
+use bitreader::BitReader;
+use common::{ParseError, Solution, Source};
+use hex::{decode, FromHexError};
+use std::ops::Range;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input<'a> = Packet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Packet, ParseError> {
+        Packet::from_hex(input)
+    }
+    fn part1(packet: &Packet) -> u64 {
+        packet.sum_version()
+    }
+    fn part2(packet: &Packet) -> u64 {
+        packet.execute()
+    }
+}
+
+pub fn puzzle_1(input: &str) -> u64 {
+    Day16::part1(&Day16::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> u64 {
+    Day16::part2(&Day16::parse(input).unwrap())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PacketLength {
+    Bit(u32),
+    Packet(u32),
+    Value,
+}
+#[derive(Debug)]
+pub struct Packet {
+    version: u8,
+    r#type: u8,
+    value: Vec<u8>,
+    children: Vec<Packet>,
+}
+
+impl Packet {
+    fn execute(&self) -> u64 {
+        match self.r#type {
+            0 => self
+                .children
+                .iter()
+                .fold(0, |acc, curr| acc + curr.execute()),
+            1 => self.children.iter().map(|c| c.execute()).product(),
+            2 => self
+                .children
+                .iter()
+                .map(|c| c.execute())
+                .reduce(Ord::min)
+                .unwrap(),
+            3 => self
+                .children
+                .iter()
+                .map(|c| c.execute())
+                .reduce(Ord::max)
+                .unwrap(),
+            4 => self.get_value(),
+            5 => {
+                if self.children[0].execute() > self.children[1].execute() {
+                    1
+                } else {
+                    0
+                }
+            }
+            6 => {
+                if self.children[0].execute() < self.children[1].execute() {
+                    1
+                } else {
+                    0
+                }
+            }
+            7 => {
+                if self.children[0].execute() == self.children[1].execute() {
+                    1
+                } else {
+                    0
+                }
+            }
+            n => unreachable!("packet types have 3 bits, parsing checks {}'s operands", n),
+        }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+            .iter()
+            .fold(0u64, |acc, &curr| acc * 16 + curr as u64)
+    }
+
+    fn sum_version(&self) -> u64 {
+        self.children
+            .iter()
+            .fold(self.version as u64, |acc, node| acc + node.sum_version())
+    }
+    fn from_hex(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(Day16::DAY, input);
+        let hex_str = input.trim_end();
+        let buf = decode(hex_str).map_err(|e| match e {
+            FromHexError::InvalidHexCharacter { c, index } => {
+                src.error(&hex_str[index..index + c.len_utf8()], "a hexadecimal digit")
+            }
+            _ => src.missing(hex_str, "an even number of hexadecimal digits"),
+        })?;
+        let mut reader = BitReader::new(&buf);
+        Self::from_reader(&mut reader).map_err(|e| {
+            let digits = e.bits.start as usize / 4..e.bits.end.div_ceil(4) as usize;
+            src.error(&hex_str[digits], e.expected)
+        })
+    }
+    fn from_reader(reader: &mut BitReader) -> Result<Packet, Malformed> {
+        if reader.remaining() < 8 {
+            return Err(Malformed::rest(reader, "another packet"));
+        }
+        let start = reader.position();
+        let version = reader
+            .read_u8(3)
+            .map_err(|_| Malformed::rest(reader, "a packet version"))?;
+        let r#type = reader
+            .read_u8(3)
+            .map_err(|_| Malformed::rest(reader, "a packet type"))?;
+        let value = Vec::<u8>::new();
+        let length = match r#type {
+            4 => PacketLength::Value,
+            _ => {
+                let is_length_by_packet = reader
+                    .read_bool()
+                    .map_err(|_| Malformed::rest(reader, "a packet length type"))?;
+                let bits = if is_length_by_packet { 11 } else { 15 };
+                let length = reader
+                    .read_u32(bits)
+                    .map_err(|_| Malformed::rest(reader, "a packet length"))?;
+                if is_length_by_packet {
+                    PacketLength::Packet(length)
+                } else {
+                    PacketLength::Bit(length)
+                }
+            }
+        };
+        let mut packet = Packet {
+            version,
+            r#type,
+            value,
+            children: Vec::new(),
+        };
+        match length {
+            PacketLength::Bit(n) => {
+                let bit_start = reader.position();
+                loop {
+                    let bits_left = reader.position() - bit_start;
+                    if bits_left == n as u64 {
+                        break;
+                    } else if bits_left > n as u64 {
+                        return Err(Malformed::since(
+                            bit_start,
+                            reader,
+                            "sub-packets matching their bit length",
+                        ));
+                    }
+                    packet.children.push(Packet::from_reader(reader)?);
+                }
+            }
+            PacketLength::Packet(n) => {
+                for _ in 0..n {
+                    packet.children.push(Packet::from_reader(reader)?);
+                }
+            }
+            PacketLength::Value => loop {
+                let group = Malformed::rest(reader, "a literal value group");
+                let is_last = reader.read_bool().map_err(|_| group.clone())?;
+                let digit = reader.read_u8(4).map_err(|_| group)?;
+                packet.value.push(digit);
+                if !is_last {
+                    break;
+                }
+            },
+        }
+        match (packet.r#type, packet.children.len()) {
+            (0..=3, 0) => Err(Malformed::since(start, reader, "at least one sub-packet")),
+            (5..=7, n) if n != 2 => Err(Malformed::since(start, reader, "exactly two sub-packets")),
+            _ => Ok(packet),
+        }
+    }
+}
+
+/// A packet that does not read as `expected`, spanning `bits` of the
+/// transmission.
+#[derive(Debug, Clone)]
+struct Malformed {
+    bits: Range<u64>,
+    expected: &'static str,
+}
+
+impl Malformed {
+    /// From where `reader` stands to the end of the transmission, for fields
+    /// cut short.
+    fn rest(reader: &BitReader, expected: &'static str) -> Self {
+        let position = reader.position();
+        Malformed {
+            bits: position..position + reader.remaining(),
+            expected,
+        }
+    }
+
+    /// From `start` to where `reader` stands.
+    fn since(start: u64, reader: &BitReader, expected: &'static str) -> Self {
+        Malformed {
+            bits: start..reader.position(),
+            expected,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_literal() {
+        let packet = Packet::from_hex("D2FE28").unwrap();
+        dbg!(&packet);
+        assert_eq!(packet.sum_version(), 6);
+        assert_eq!(packet.get_value(), 2021);
+    }
+
+    #[test]
+    fn two_sub_packets() {
+        let packet = Packet::from_hex("38006F45291200").unwrap();
+        assert_eq!(packet.children[0].get_value(), 10);
+        assert_eq!(packet.children[1].get_value(), 20);
+        assert_eq!(packet.sum_version(), 9);
+    }
+
+    #[test]
+    fn another_example_3_sub_packets() {
+        let packet = Packet::from_hex("EE00D40C823060").unwrap();
+        assert_eq!(packet.children.len(), 3);
+    }
+
+    #[test]
+    fn puzzle_1_sum_31_test() {
+        let packet = Packet::from_hex("A0016C880162017C3686B18A3D4780").unwrap();
+        assert_eq!(packet.sum_version(), 31);
+    }
+
+    #[test]
+    fn puzzle_2_sum_test() {
+        let packet = Packet::from_hex("C200B40A82").unwrap();
+        assert_eq!(packet.execute(), 3);
+    }
+
+    #[test]
+    fn from_hex_reports_bad_digit() {
+        let error = Packet::from_hex("D2FG28").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 4, "G"));
+    }
+
+    #[test]
+    fn from_hex_reports_truncated_packet() {
+        let error = Packet::from_hex("D2FE").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, ""));
+        assert_eq!(error.expected, "a literal value group");
+    }
+
+    #[test]
+    fn from_hex_reports_truncated_operator() {
+        let error = Packet::from_hex("160080").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, "80"));
+        assert_eq!(error.expected, "another packet");
+        let error = Packet::from_hex("C200B4").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, "B4"));
+    }
+
+    #[test]
+    fn from_hex_reports_operator_arity() {
+        // A sum holding no sub-packet, by bit length.
+        let error = Packet::from_hex("0000000000").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (1, "000000"));
+        assert_eq!(error.expected, "at least one sub-packet");
+        // A greater-than holding a single literal, by packet count.
+        let error = Packet::from_hex("16004408").unwrap_err();
+        assert_eq!(error.expected, "exactly two sub-packets");
+    }
+
+    #[test]
+    fn puzzle_2_advanced() {
+        let packet = Packet::from_hex("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(packet.execute(), 1);
+    }
+}
@@ -0,0 +1,15 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A target area about `size` steps below the launcher. Its x range holds a
+/// triangular number, so some probe stalls right above it.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let depth = size.max(2) as i64;
+    let min_y = -depth;
+    let max_y = min_y + rng.gen_range(1..=depth / 4 + 1).min(depth - 1);
+    let stall = rng.gen_range(5..=5 + depth / 10);
+    let reach = stall * (stall + 1) / 2;
+    let min_x = reach - rng.gen_range(0..=stall / 2);
+    let max_x = reach + rng.gen_range(stall..=2 * stall);
+    format!("target area: x={}..{}, y={}..{}", min_x, max_x, min_y, max_y)
+}
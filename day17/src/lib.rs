@@ -0,0 +1,114 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use std::str::FromStr;
+use regex::Regex;
+#[macro_use]
+extern crate lazy_static;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input<'a> = Target;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Target, ParseError> {
+        input.parse()
+    }
+    fn part1(target: &Target) -> i32 {
+        brute_force(target).0
+    }
+    fn part2(target: &Target) -> i32 {
+        brute_force(target).1
+    }
+}
+
+pub fn puzzle_1(input: &str) -> i32 {
+    Day17::part1(&Day17::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> i32 {
+    Day17::part2(&Day17::parse(input).unwrap())
+}
+
+fn brute_force(target: &Target) -> (i32, i32) {
+    let mut max_y = 0;
+    let mut count = 0;
+    for velocity_x in 1..=target.max_x {
+        for velocity_y in target.min_y..1000 {
+            let mut x = 0;
+            let mut y = 0;
+            let mut vx = velocity_x;
+            let mut vy = velocity_y;
+            let mut _max_y = 0;
+            for _ in 0..1000 {
+                x += vx;
+                y += vy;
+                if vx >= 1 { vx -= 1 };
+                vy -= 1;
+                _max_y = Ord::max(y, _max_y);
+                if target.contains(x, y) {
+                    count += 1;
+                    max_y = Ord::max(max_y, _max_y);
+                    break;
+                } else if y < target.min_y || x > target.max_x{
+                    break;
+                }
+            }
+        }
+    }
+    (max_y, count)
+}
+
+#[derive(Debug)]
+pub struct Target {
+    min_x: i32,
+    min_y: i32,
+    max_x: i32,
+    max_y: i32,
+}
+
+impl Target {
+    fn contains(&self, x:i32, y:i32) -> bool {
+        x >= self.min_x && x <= self.max_x && y >= self.min_y && y <= self.max_y
+    }
+}
+
+impl FromStr for Target {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref INPUT_PATTERN: Regex = Regex::new("target area: x=(\\d+)..(\\d+), y=(-\\d+)..(-\\d+)$").unwrap();
+        }
+        let src = Source::new(Day17::DAY, s);
+        let line = s.trim_end();
+        let captuers = INPUT_PATTERN
+            .captures(line)
+            .ok_or_else(|| src.error(line, "`target area: x=A..B, y=C..D`"))?;
+        let bound = |i: usize| src.parse(captuers.get(i).unwrap().as_str(), "a bound");
+        Ok(Target{
+            min_x: bound(1)?,
+            max_x: bound(2)?,
+            min_y: bound(3)?,
+            max_y: bound(4)?,
+        })
+    }
+
+    type Err = ParseError;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn puzzle_1_working() {
+        let input = "target area: x=20..30, y=-10..-5";
+        assert_eq!(puzzle_1(input), 45)
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = "target area: x=20..30, y=-10..-5";
+        assert_eq!(puzzle_2(input), 112)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` reduced snailfish numbers, nested at most four pairs deep.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| pair(&mut rng, 1))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn pair(rng: &mut StdRng, depth: usize) -> String {
+    let element = |rng: &mut StdRng| {
+        if depth < 4 && rng.gen_bool(0.6) {
+            pair(rng, depth + 1)
+        } else {
+            rng.gen_range(0..=9).to_string()
+        }
+    };
+    let left = element(rng);
+    format!("[{},{}]", left, element(rng))
+}
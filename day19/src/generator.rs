@@ -0,0 +1,75 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+type Vector = [i64; 3];
+
+/// Beacons seen by `size` scanners, each with its own orientation. Every
+/// scanner shares at least 12 beacons with one placed before it.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut scanners: Vec<Vector> = vec![[0; 3]];
+    let mut beacons = BTreeSet::new();
+    for _ in 0..25 {
+        beacons.insert(within(&mut rng, [0; 3], [0; 3]));
+    }
+    while scanners.len() < size.max(2) {
+        let parent = *scanners.choose(&mut rng).unwrap();
+        let offset: Vector = [0, 1, 2].map(|_| rng.gen_range(-1200..=1200));
+        let scanner = [0, 1, 2].map(|i| parent[i] + offset[i]);
+        for _ in 0..12 {
+            beacons.insert(within(&mut rng, parent, scanner));
+        }
+        for _ in 0..13 {
+            beacons.insert(within(&mut rng, scanner, scanner));
+        }
+        scanners.push(scanner);
+    }
+    let rotations = rotations();
+    scanners
+        .iter()
+        .enumerate()
+        .map(|(id, scanner)| {
+            let rotation = match id {
+                0 => rotations[0],
+                _ => *rotations.choose(&mut rng).unwrap(),
+            };
+            let mut lines = vec![format!("--- scanner {} ---", id)];
+            lines.extend(
+                beacons
+                    .iter()
+                    .filter(|b| (0..3).all(|i| (b[i] - scanner[i]).abs() <= 1000))
+                    .map(|b| {
+                        let [x, y, z] = rotate(rotation, [0, 1, 2].map(|i| b[i] - scanner[i]));
+                        format!("{},{},{}", x, y, z)
+                    }),
+            );
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// A point in range of the scanners at both `a` and `b`.
+fn within(rng: &mut StdRng, a: Vector, b: Vector) -> Vector {
+    [0, 1, 2].map(|i| rng.gen_range(a[i].max(b[i]) - 1000..=a[i].min(b[i]) + 1000))
+}
+
+/// The 24 rotations, as the axis each coordinate is read from and its sign.
+fn rotations() -> Vec<([usize; 3], Vector)> {
+    let axes = [[0, 1, 2], [1, 2, 0], [2, 0, 1], [0, 2, 1], [2, 1, 0], [1, 0, 2]];
+    let mut rotations = Vec::new();
+    for (i, axes) in axes.into_iter().enumerate() {
+        let parity = if i < 3 { 1 } else { -1 };
+        for signs in 0..8 {
+            let sign = [0, 1, 2].map(|bit| if signs >> bit & 1 == 1 { -1 } else { 1 });
+            if sign.iter().product::<i64>() == parity {
+                rotations.push((axes, sign));
+            }
+        }
+    }
+    rotations
+}
+
+fn rotate((axes, sign): ([usize; 3], Vector), v: Vector) -> Vector {
+    [0, 1, 2].map(|i| sign[i] * v[axes[i]])
+}
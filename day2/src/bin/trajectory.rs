@@ -0,0 +1,35 @@
+use clap::{Parser, ValueEnum};
+use common::load;
+use day2::{parse, trajectory, Aimed, Direct};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Export {
+    /// One row per command with the state of both models
+    Csv,
+    /// The depth profile of both models
+    Svg,
+}
+
+/// Records the course of both movement models after every command
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Export format of the trajectories
+    #[arg(long, value_enum, default_value_t = Export::Csv)]
+    format: Export,
+}
+
+fn main() {
+    let args = Args::parse();
+    let commands = load(&args.input, parse);
+    let direct = trajectory::record::<Direct>(&commands);
+    let aimed = trajectory::record::<Aimed>(&commands);
+    let models = [("direct", &direct), ("aimed", &aimed)];
+    match args.format {
+        Export::Csv => print!("{}", trajectory::csv(&models)),
+        Export::Svg => print!("{}", trajectory::svg(&models)),
+    }
+}
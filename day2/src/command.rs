@@ -0,0 +1,57 @@
+use crate::Day2;
+use common::{ParseError, Solution, Source};
+use std::fmt;
+
+const VERBS: &str = "a command `forward`, `down`, `up`, `back`, `surface` or `reset-aim`";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    /// Moves backwards, the opposite of `Forward`.
+    Back(i32),
+    /// Rises straight back to the surface.
+    Surface,
+    /// Levels the submarine out, for models that aim.
+    ResetAim,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    let src = Source::new(Day2::DAY, input);
+    input.lines().map(|l| Command::parse(src, l)).collect()
+}
+
+impl Command {
+    fn parse(src: Source, line: &str) -> Result<Self, ParseError> {
+        let mut tokens = line.split_ascii_whitespace();
+        let verb = tokens.next().ok_or_else(|| src.missing(line, VERBS))?;
+        let command = match verb {
+            "forward" => Command::Forward(src.next(&mut tokens, line, "an amount")?),
+            "down" => Command::Down(src.next(&mut tokens, line, "an amount")?),
+            "up" => Command::Up(src.next(&mut tokens, line, "an amount")?),
+            "back" => Command::Back(src.next(&mut tokens, line, "an amount")?),
+            "surface" => Command::Surface,
+            "reset-aim" => Command::ResetAim,
+            _ => return Err(src.error(verb, VERBS)),
+        };
+        match tokens.next() {
+            Some(extra) => Err(src.error(extra, "end of line")),
+            None => Ok(command),
+        }
+    }
+}
+
+/// Writes the command back the way it is parsed.
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(n) => write!(f, "forward {}", n),
+            Command::Down(n) => write!(f, "down {}", n),
+            Command::Up(n) => write!(f, "up {}", n),
+            Command::Back(n) => write!(f, "back {}", n),
+            Command::Surface => write!(f, "surface"),
+            Command::ResetAim => write!(f, "reset-aim"),
+        }
+    }
+}
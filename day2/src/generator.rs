@@ -0,0 +1,25 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A course of `size` commands that never takes the submarine above the
+/// surface.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut depth = 0;
+    (0..size.max(1))
+        .map(|_| {
+            let n = rng.gen_range(1..=9);
+            match rng.gen_range(0..3) {
+                0 => format!("forward {}", n),
+                1 if depth >= n => {
+                    depth -= n;
+                    format!("up {}", n)
+                }
+                _ => {
+                    depth += n;
+                    format!("down {}", n)
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,88 @@
+mod command;
+pub mod generator;
+mod model;
+pub mod trajectory;
+use common::{ParseError, Solution};
+
+pub use command::{parse, Command};
+pub use model::{follow, Aimed, Direct, Model, Position};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Vec<Command>) -> i32 {
+        puzzle_1(input)
+    }
+    fn part2(input: &Vec<Command>) -> i32 {
+        puzzle_2(input)
+    }
+}
+
+pub fn puzzle_1(commands: &[Command]) -> i32 {
+    product::<Direct>(commands)
+}
+
+pub fn puzzle_2(commands: &[Command]) -> i32 {
+    product::<Aimed>(commands)
+}
+
+/// The puzzle answer: final horizontal position times final depth.
+fn product<M: Model>(commands: &[Command]) -> i32 {
+    let Position { horizontal, depth } = follow::<M>(commands).position();
+    horizontal * depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+    #[test]
+    fn puzzle_working() {
+        let commands = parse(EXAMPLE).unwrap();
+        assert_eq!((puzzle_1(&commands), puzzle_2(&commands)), (150, 900));
+    }
+
+    #[test]
+    fn extra_instructions() {
+        let commands =
+            parse("down 4\nforward 3\nreset-aim\nback 1\nforward 2\nsurface\ndown 2").unwrap();
+        assert_eq!(
+            follow::<Direct>(&commands).position(),
+            Position {
+                horizontal: 4,
+                depth: 2
+            }
+        );
+        let aimed = follow::<Aimed>(&commands);
+        assert_eq!(
+            aimed.position(),
+            Position {
+                horizontal: 4,
+                depth: 0
+            }
+        );
+        assert_eq!(aimed.aim, 2);
+    }
+
+    #[test]
+    fn parse_reports_unknown_verb() {
+        let error = parse("forward 5\nsideways 2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "sideways");
+        let error = parse("surface 3").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (1, 9, "end of line")
+        );
+    }
+}
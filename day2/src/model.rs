@@ -0,0 +1,83 @@
+use crate::Command;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i32,
+    pub depth: i32,
+}
+
+/// A way of moving the submarine by its commands. A new model goes from
+/// the default state, so following a course is the same fold for all.
+pub trait Model: Default {
+    fn apply(&mut self, command: Command);
+    fn position(&self) -> Position;
+    /// Models without an aim keep it level.
+    fn aim(&self) -> i32 {
+        0
+    }
+}
+
+/// Follows `commands` from the start, with the movement of `M`.
+pub fn follow<M: Model>(commands: &[Command]) -> M {
+    commands.iter().fold(M::default(), |mut model, &command| {
+        model.apply(command);
+        model
+    })
+}
+
+/// The first reading of the manual: `down` and `up` change the depth.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Direct {
+    pub position: Position,
+}
+
+impl Model for Direct {
+    fn apply(&mut self, command: Command) {
+        let p = &mut self.position;
+        match command {
+            Command::Forward(n) => p.horizontal += n,
+            Command::Back(n) => p.horizontal -= n,
+            Command::Down(n) => p.depth += n,
+            Command::Up(n) => p.depth -= n,
+            Command::Surface => p.depth = 0,
+            Command::ResetAim => {}
+        }
+    }
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// The manual read properly: `down` and `up` change the aim, and moving
+/// changes the depth along it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Aimed {
+    pub position: Position,
+    pub aim: i32,
+}
+
+impl Model for Aimed {
+    fn apply(&mut self, command: Command) {
+        let p = &mut self.position;
+        match command {
+            Command::Forward(n) => {
+                p.horizontal += n;
+                p.depth += self.aim * n;
+            }
+            Command::Back(n) => {
+                p.horizontal -= n;
+                p.depth -= self.aim * n;
+            }
+            Command::Down(n) => self.aim += n,
+            Command::Up(n) => self.aim -= n,
+            Command::Surface => p.depth = 0,
+            Command::ResetAim => self.aim = 0,
+        }
+    }
+    fn position(&self) -> Position {
+        self.position
+    }
+    fn aim(&self) -> i32 {
+        self.aim
+    }
+}
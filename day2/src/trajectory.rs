@@ -0,0 +1,169 @@
+use crate::{Command, Model, Position};
+use std::fmt::Write;
+
+/// The state of a model after a command, or at the start for `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub command: Option<Command>,
+    pub position: Position,
+    pub aim: i32,
+}
+
+/// Every state a model goes through while following a course.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    pub steps: Vec<Step>,
+}
+
+/// Follows `commands` with `M`, recording the state after every one of them.
+pub fn record<M: Model>(commands: &[Command]) -> Trajectory {
+    let mut model = M::default();
+    let mut steps = vec![step(None, &model)];
+    for &command in commands {
+        model.apply(command);
+        steps.push(step(Some(command), &model));
+    }
+    Trajectory { steps }
+}
+
+fn step(command: Option<Command>, model: &impl Model) -> Step {
+    Step {
+        command,
+        position: model.position(),
+        aim: model.aim(),
+    }
+}
+
+/// The trajectories of several models along the same course as CSV, one row
+/// per step and three columns per model, so models diff step by step.
+pub fn csv(models: &[(&str, &Trajectory)]) -> String {
+    let mut out = String::from("step,command");
+    for (name, _) in models {
+        write!(out, ",{0}_horizontal,{0}_depth,{0}_aim", name).unwrap();
+    }
+    out.push('\n');
+    let steps = models.iter().map(|(_, t)| t.steps.len()).max().unwrap_or(0);
+    for i in 0..steps {
+        let command = models
+            .iter()
+            .find_map(|(_, t)| t.steps.get(i))
+            .and_then(|s| s.command);
+        write!(
+            out,
+            "{},{}",
+            i,
+            command.map(|c| c.to_string()).unwrap_or_default()
+        )
+        .unwrap();
+        for (_, trajectory) in models {
+            match trajectory.steps.get(i) {
+                Some(s) => write!(
+                    out,
+                    ",{},{},{}",
+                    s.position.horizontal, s.position.depth, s.aim
+                ),
+                None => write!(out, ",,,"),
+            }
+            .unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+const WIDTH: f64 = 800.0;
+const PANEL: f64 = 240.0;
+const MARGIN: f64 = 40.0;
+
+/// Depth profiles of several models as an SVG, one panel each. Depths grow
+/// downwards and every panel is scaled to its own course, as the models
+/// easily differ by orders of magnitude.
+pub fn svg(models: &[(&str, &Trajectory)]) -> String {
+    let height = PANEL * models.len() as f64;
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" font-family="sans-serif" font-size="12">"#,
+        WIDTH, height
+    )
+    .unwrap();
+    for (i, (name, trajectory)) in models.iter().enumerate() {
+        let top = PANEL * i as f64;
+        let positions = trajectory.steps.iter().map(|s| s.position);
+        let (min_x, max_x) = range(positions.clone().map(|p| p.horizontal));
+        let (min_y, max_y) = range(positions.clone().map(|p| p.depth));
+        let scale = |value: i32, min: i32, max: i32, length: f64| {
+            (value - min) as f64 / (max - min).max(1) as f64 * length
+        };
+        let points = positions
+            .map(|p| {
+                format!(
+                    "{:.1},{:.1}",
+                    MARGIN + scale(p.horizontal, min_x, max_x, WIDTH - 2.0 * MARGIN),
+                    top + MARGIN + scale(p.depth, min_y, max_y, PANEL - 2.0 * MARGIN)
+                )
+            })
+            .collect::<Vec<_>>();
+        writeln!(
+            out,
+            r#"  <text x="{}" y="{}">{}: horizontal {}..{}, depth {}..{}</text>"#,
+            MARGIN,
+            top + MARGIN / 2.0,
+            name,
+            min_x,
+            max_x,
+            min_y,
+            max_y
+        )
+        .unwrap();
+        writeln!(
+            out,
+            r#"  <polyline fill="none" stroke="steelblue" points="{}"/>"#,
+            points.join(" ")
+        )
+        .unwrap();
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+fn range(values: impl Iterator<Item = i32>) -> (i32, i32) {
+    values.fold((0, 0), |(min, max), v| (min.min(v), max.max(v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, Aimed, Direct};
+
+    #[test]
+    fn record_both_models() {
+        let commands = parse("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap();
+        let direct = record::<Direct>(&commands);
+        let aimed = record::<Aimed>(&commands);
+        assert_eq!(aimed.steps.len(), 7);
+        assert_eq!(aimed.steps[0].command, None);
+        assert_eq!(
+            aimed.steps[3],
+            Step {
+                command: Some(Command::Forward(8)),
+                position: Position {
+                    horizontal: 13,
+                    depth: 40
+                },
+                aim: 5
+            }
+        );
+        let csv = csv(&[("direct", &direct), ("aimed", &aimed)]);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "step,command,direct_horizontal,direct_depth,direct_aim,aimed_horizontal,aimed_depth,aimed_aim"
+        );
+        assert_eq!(lines[1], "0,,0,0,0,0,0,0");
+        assert_eq!(lines[7], "6,forward 2,15,10,0,15,60,10");
+        let svg = svg(&[("direct", &direct), ("aimed", &aimed)]);
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert!(svg.contains("aimed: horizontal 0..15, depth 0..60"));
+    }
+}
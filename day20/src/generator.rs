@@ -0,0 +1,21 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A random enhancement algorithm and a `size` by `size` input image. When
+/// the algorithm lights up dark areas, it also turns lit areas dark, so the
+/// count of lit pixels stays finite after every even step.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut pixel = || if rng.gen() { '#' } else { '.' };
+    let mut algorithm = (0..512).map(|_| pixel()).collect::<Vec<_>>();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let image = (0..size.max(1))
+        .map(|_| (0..size.max(1)).map(|_| pixel()).collect::<String>())
+        .collect::<Vec<_>>();
+    format!(
+        "{}\n\n{}",
+        algorithm.into_iter().collect::<String>(),
+        image.join("\n")
+    )
+}
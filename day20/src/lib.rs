@@ -0,0 +1,136 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use grid::{Point, SparseGrid};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        Puzzle::parse(Source::new(Self::DAY, input))
+    }
+    fn part1(puzzle: &Puzzle) -> usize {
+        let mut p = puzzle.picture.clone();
+        for i in 0..2 {
+            p = p.next(&puzzle.instruction, i % 2 == 0)
+        }
+        p.pixels.len()
+    }
+    fn part2(puzzle: &Puzzle) -> usize {
+        let mut p = puzzle.picture.clone();
+        for i in 0..50 {
+            p = p.next(&puzzle.instruction, i % 2 == 0)
+        }
+        p.pixels.len()
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day20::part1(&Day20::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    Day20::part2(&Day20::parse(input).unwrap())
+}
+
+pub struct Puzzle {
+    picture: Picture,
+    instruction: Vec<char>,
+}
+
+impl Puzzle {
+    fn parse(src: Source) -> Result<Puzzle, ParseError> {
+        let (instruction, picture) = src
+            .text
+            .trim()
+            .split_once("\n\n")
+            .ok_or_else(|| src.missing(src.text, "an input image"))?;
+        if let Some((i, c)) = instruction.char_indices().find(|(_, c)| !matches!(c, '#' | '.')) {
+            return Err(src.error(&instruction[i..i + c.len_utf8()], "`#` or `.`"));
+        }
+        if instruction.len() != 512 {
+            return Err(src.error(instruction, "an enhancement algorithm of 512 pixels"));
+        }
+        Ok(Puzzle {
+            picture: Picture::parse(src, picture)?,
+            instruction: instruction.chars().collect(),
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Picture {
+    pixels: SparseGrid<bool>,
+}
+
+impl Picture {
+    fn next(&self, instruction: &[char], bright: bool) -> Picture {
+        let mut pic = Picture::default();
+        let (min, max) = self.pixels.bounds().unwrap_or_default();
+        for y in min.y - 2..max.y + 2 {
+            for x in min.x - 2..max.x + 2 {
+                let lit = match instruction[self.point_value(instruction, Point::new(x, y), bright)] {
+                    '#' => instruction[0] != '#' || !bright,
+                    _ => instruction[0] == '#' && bright,
+                };
+                if lit {
+                    pic.pixels.insert(Point::new(x, y), true);
+                }
+            }
+        }
+        pic
+    }
+    fn point_value(&self, instruction: &[char], pos: Point, bright: bool) -> usize {
+        // Reading order from the bottom right, the least significant bit
+        let mut window = pos.neighbors8().collect::<Vec<_>>();
+        window.insert(4, pos);
+        window
+            .iter()
+            .rev()
+            .enumerate()
+            .fold(0, |acc, (index, &point)| {
+                let mut n = 0;
+                if instruction[0] == '#' {
+                    // Conditionally reverse the bool to handle infinity brightness
+                    if self.pixels.contains(point) == bright {
+                        n = 1 << index
+                    }
+                } else if self.pixels.contains(point) {
+                    n = 1 << index
+                }
+                acc + n
+            })
+    }
+    fn parse(src: Source, s: &str) -> Result<Self, ParseError> {
+        Ok(Picture {
+            pixels: SparseGrid::parse(src, s)?,
+        })
+    }
+}
+
+impl std::fmt::Display for Picture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_1_test_case() {
+        let input = std::fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 35)
+    }
+
+    #[test]
+    fn puzzle_2_test_case() {
+        let input = std::fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 3351)
+    }
+}
@@ -0,0 +1,12 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Random starting positions of both players. The game has no size, so
+/// `size` is ignored.
+pub fn generate(_size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}",
+        rng.gen_range(1..=10),
+        rng.gen_range(1..=10)
+    )
+}
@@ -0,0 +1,23 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` reboot steps. Like the puzzle, the first 20 stay within the
+/// initialization region and the rest span a much larger one.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|i| {
+            let (extent, side) = match i {
+                0..=19 => (50, 10..=50),
+                _ => (100_000, 1_000..=30_000),
+            };
+            let ranges = ["x", "y", "z"].map(|axis| {
+                let length: i64 = rng.gen_range(side.clone());
+                let min = rng.gen_range(-extent..=extent - length);
+                format!("{}={}..{}", axis, min, min + length)
+            });
+            let state = if i == 0 || rng.gen_bool(0.6) { "on" } else { "off" };
+            format!("{} {}", state, ranges.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
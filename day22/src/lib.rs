@@ -0,0 +1,189 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+#[macro_use]
+extern crate lazy_static;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input<'a> = Vec<Command>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        let src = Source::new(Self::DAY, input);
+        let commands = input
+            .lines()
+            .map(|l| parse_command(src, l))
+            .collect::<Result<Vec<_>, _>>()?;
+        if commands.is_empty() {
+            return Err(src.missing(input, "a reboot step"));
+        }
+        Ok(commands)
+    }
+    fn part1(commands: &Vec<Command>) -> usize {
+        reboot_initialization_area(commands)
+    }
+    fn part2(commands: &Vec<Command>) -> i64 {
+        reboot(commands)
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day22::part1(&Day22::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> i64 {
+    Day22::part2(&Day22::parse(input).unwrap())
+}
+
+fn reboot_initialization_area(commands: &[Command]) -> usize {
+    let mut space = Space::new();
+    commands
+        .iter()
+        .filter(|cmd| match cmd {
+            Command(_, (x, mx), (y, my), (z, mz)) => {
+                [x, mx, y, my, z, mz].iter().all(|&&i| (-50..=50).contains(&i))
+            }
+        })
+        .for_each(|&cmd| match cmd {
+            Command(true, (x0, x1), (y0, y1), (z0, z1)) => {
+                for x in x0..=x1 {
+                    for y in y0..=y1 {
+                        for z in z0..=z1 {
+                            space.insert(Point { x, y, z });
+                        }
+                    }
+                }
+            }
+            Command(false, (x0, x1), (y0, y1), (z0, z1)) => {
+                for x in x0..=x1 {
+                    for y in y0..=y1 {
+                        for z in z0..=z1 {
+                            space.remove(&Point { x, y, z });
+                        }
+                    }
+                }
+            }
+        });
+    space.len()
+}
+
+fn reboot(commands: &[Command]) -> i64 {
+    let mut x_arr = Vec::<i32>::new();
+    let mut y_arr = Vec::<i32>::new();
+    let mut z_arr = Vec::<i32>::new();
+
+    commands.iter().for_each(|cmd| match cmd {
+        Command(_, (x0, x1), (y0, y1), (z0, z1)) => {
+            x_arr.push(*x0);
+            x_arr.push(*x1 + 1);
+            y_arr.push(*y0);
+            y_arr.push(*y1 + 1);
+            z_arr.push(*z0);
+            z_arr.push(*z1 + 1);
+        }
+    });
+    let sorted_x = x_arr.into_iter().sorted().collect_vec();
+    let sorted_y = y_arr.into_iter().sorted().collect_vec();
+    let sorted_z = z_arr.into_iter().sorted().collect_vec();
+
+    let mut hyper_grid = vec![vec![vec![false; sorted_z.len()]; sorted_y.len()]; sorted_x.len()];
+    commands.iter().for_each(|&cmd| match cmd {
+        Command(op, (x0, x1), (y0, y1), (z0, z1)) => {
+            for plane in &mut hyper_grid[pos(&sorted_x, &x0)..pos(&sorted_x, &(x1 + 1))] {
+                for row in &mut plane[pos(&sorted_y, &y0)..pos(&sorted_y, &(y1 + 1))] {
+                    row[pos(&sorted_z, &z0)..pos(&sorted_z, &(z1 + 1))].fill(op);
+                }
+            }
+        }
+    });
+
+    let mut sum = 0i64;
+
+    for x in 0..sorted_x.len() - 1 {
+        for y in 0..sorted_y.len() - 1 {
+            for z in 0..sorted_z.len() - 1 {
+                if hyper_grid[x][y][z] {
+                    sum += (sorted_x[x + 1] - sorted_x[x]) as i64
+                        * (sorted_y[y + 1] - sorted_y[y]) as i64
+                        * (sorted_z[z + 1] - sorted_z[z]) as i64;
+                }
+            }
+        }
+    }
+    sum
+}
+
+fn pos(v: &[i32], i: &i32) -> usize {
+    v.iter().position(|x| x == i).unwrap()
+}
+
+type Space = BTreeSet<Point>;
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Command(bool, (i32, i32), (i32, i32), (i32, i32));
+
+fn parse_command(src: Source, input: &str) -> Result<Command, ParseError> {
+    lazy_static! {
+        static ref PATTERN:Regex = Regex::new(r"(?P<op>on|off) x=(?P<min_x>-?\d+)..(?P<max_x>-?\d+),y=(?P<min_y>-?\d+)..(?P<max_y>-?\d+),z=(?P<min_z>-?\d+)..(?P<max_z>-?\d+)$").unwrap();
+    }
+    let cap = PATTERN
+        .captures(input)
+        .ok_or_else(|| src.error(input, "a step `on|off x=A..B,y=C..D,z=E..F`"))?;
+    let range = |axis: &str| -> Result<(i32, i32), ParseError> {
+        let min = cap.name(&format!("min_{}", axis)).unwrap().as_str();
+        let max = cap.name(&format!("max_{}", axis)).unwrap().as_str();
+        let (min, max_value) = (src.parse(min, "a bound")?, src.parse(max, "a bound")?);
+        if min > max_value {
+            return Err(src.error(max, format!("a bound no less than {}", min)));
+        }
+        Ok((min, max_value))
+    };
+    let on = &cap["op"] == "on";
+    Ok(Command(on, range("x")?, range("y")?, range("z")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_1_working() {
+        let test_case = std::fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&test_case), 590784);
+    }
+
+    #[test]
+    fn parse_reports_reversed_range() {
+        let error = Day22::parse("on x=10..12,y=10..12,z=10..12\noff x=9..11,y=11..9,z=9..11\n")
+            .unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 19, "9"));
+        assert_eq!(error.expected, "a bound no less than 11");
+        assert_eq!(Day22::parse("").unwrap_err().expected, "a reboot step");
+    }
+
+    #[test]
+    fn puzzle_2_working_small() {
+        let test_case = std::fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&test_case), 590784);
+    }
+
+    #[test]
+    fn puzzle_2_working_big() {
+        let test_case = std::fs::read_to_string("test_case_b").unwrap();
+        assert_eq!(puzzle_2(&test_case), 2758514936282235);
+    }
+}
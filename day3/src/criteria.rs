@@ -0,0 +1,96 @@
+use num_bigint::BigUint;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    MostCommon,
+    LeastCommon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+/// Which bit a rate or a rating picks at every position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Criteria {
+    pub keep: Keep,
+    /// The bit picked when zeros and ones are equally common.
+    pub tie: bool,
+    /// The order ratings go through the bits in. Rates don't depend on it.
+    pub order: BitOrder,
+}
+
+impl Criteria {
+    pub const GAMMA: Criteria = Criteria::new(Keep::MostCommon, true);
+    pub const EPSILON: Criteria = Criteria::new(Keep::LeastCommon, false);
+    pub const OXYGEN: Criteria = Criteria::new(Keep::MostCommon, true);
+    pub const CO2: Criteria = Criteria::new(Keep::LeastCommon, false);
+
+    /// Criteria going through the bits from the most significant one.
+    pub const fn new(keep: Keep, tie: bool) -> Self {
+        Criteria {
+            keep,
+            tie,
+            order: BitOrder::MsbFirst,
+        }
+    }
+
+    pub const fn order(self, order: BitOrder) -> Self {
+        Criteria { order, ..self }
+    }
+
+    /// The bit picked out of `zeros` zeros and `ones` ones.
+    pub fn pick(&self, zeros: usize, ones: usize) -> bool {
+        match (self.keep, ones.cmp(&zeros)) {
+            (_, std::cmp::Ordering::Equal) => self.tie,
+            (Keep::MostCommon, order) => order.is_gt(),
+            (Keep::LeastCommon, order) => order.is_lt(),
+        }
+    }
+}
+
+/// One bit position of a rating, and the lines it ruled out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elimination {
+    /// Position of the bit, counted from the left.
+    pub bit: usize,
+    pub zeros: usize,
+    pub ones: usize,
+    pub kept: bool,
+    /// 1-based lines of the report, in ascending order.
+    pub eliminated: Vec<usize>,
+}
+
+/// A rating along with how the numbers were narrowed down to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub rating: BigUint,
+    /// 1-based line of the rating in the report.
+    pub line: usize,
+    pub steps: Vec<Elimination>,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            let lines = step
+                .eliminated
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>();
+            writeln!(
+                f,
+                "bit {}: {} zeros, {} ones, keeping {}, eliminating lines {}",
+                step.bit,
+                step.zeros,
+                step.ones,
+                step.kept as u8,
+                lines.join(", ")
+            )?;
+        }
+        write!(f, "rating {} on line {}", self.rating, self.line)
+    }
+}
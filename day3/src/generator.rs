@@ -0,0 +1,37 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// A diagnostic report of `size` distinct binary numbers, 12 bits wide or
+/// wider when 12 bits can't hold that many. As in the puzzle, any prefix
+/// shared by two numbers goes on with both bits, so the rating filters
+/// always narrow down to exactly one number.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let width = 12.max(usize::BITS - (size - 1).leading_zeros()) as usize;
+    let mut lines = Vec::with_capacity(size);
+    numbers(&mut rng, &mut String::new(), width, size, &mut lines);
+    lines.shuffle(&mut rng);
+    lines.join("\n")
+}
+
+/// Appends `count` numbers starting with `prefix` to `lines`.
+fn numbers(
+    rng: &mut StdRng,
+    prefix: &mut String,
+    width: usize,
+    count: usize,
+    lines: &mut Vec<String>,
+) {
+    if count == 1 {
+        let rest = (prefix.len()..width).map(|_| if rng.gen() { '1' } else { '0' });
+        lines.push(prefix.chars().chain(rest).collect());
+        return;
+    }
+    let half = 1 << (width - prefix.len() - 1);
+    let zeros = rng.gen_range(count.saturating_sub(half).max(1)..=half.min(count - 1));
+    for (bit, count) in [('0', zeros), ('1', count - zeros)] {
+        prefix.push(bit);
+        numbers(rng, prefix, width, count, lines);
+        prefix.pop();
+    }
+}
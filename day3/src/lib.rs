@@ -0,0 +1,105 @@
+mod criteria;
+pub mod generator;
+mod report;
+use common::{ParseError, Solution, Source};
+use num_bigint::BigUint;
+
+pub use criteria::{BitOrder, Criteria, Elimination, Explanation, Keep};
+pub use report::Report;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input<'a> = Report;
+    type Answer1 = BigUint;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Report, ParseError> {
+        parse(input)
+    }
+    fn part1(report: &Report) -> BigUint {
+        puzzle_1(report)
+    }
+    fn part2(report: &Report) -> BigUint {
+        puzzle_2(report)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Report, ParseError> {
+    Report::parse(Source::new(Day3::DAY, input))
+}
+
+pub fn puzzle_1(report: &Report) -> BigUint {
+    report.gamma_rate() * report.epsilon_rate()
+}
+
+pub fn puzzle_2(report: &Report) -> BigUint {
+    report.o2_rating() * report.co2_rating()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+    #[test]
+    fn puzzle_working() {
+        let report = parse(EXAMPLE).unwrap();
+        assert_eq!(puzzle_1(&report), BigUint::from(198u32));
+        assert_eq!(puzzle_2(&report), BigUint::from(230u32));
+    }
+
+    #[test]
+    fn wide_report() {
+        // 70 bits, more than a single word holds.
+        let numbers = ["1", "0", "1"].map(|head| format!("{}{}", head, "0".repeat(69)));
+        let input = format!("{}\n{}1\n{}", numbers[0], &numbers[1][..69], numbers[2]);
+        let report = parse(&input).unwrap();
+        assert_eq!(report.width(), 70);
+        assert_eq!(report.gamma_rate(), BigUint::from(1u32) << 69);
+        assert_eq!(report.o2_rating(), BigUint::from(1u32) << 69);
+        assert_eq!(report.co2_rating(), BigUint::from(1u32));
+    }
+
+    #[test]
+    fn custom_criteria() {
+        let report = parse(EXAMPLE).unwrap();
+        let least_ones = Criteria::new(Keep::LeastCommon, true);
+        assert_eq!(report.rate(least_ones), BigUint::from(9u32));
+        assert_eq!(report.rating(least_ones), BigUint::from(0b01111u32));
+        let explanation = report.explain(Criteria::CO2);
+        assert_eq!(explanation.rating, BigUint::from(10u32));
+        assert_eq!(explanation.line, 12);
+        assert_eq!(
+            explanation.steps[0],
+            Elimination {
+                bit: 0,
+                zeros: 5,
+                ones: 7,
+                kept: false,
+                eliminated: vec![2, 3, 4, 5, 8, 9, 10],
+            }
+        );
+        assert_eq!(explanation.steps.len(), 3);
+        let lsb = report.explain(Criteria::OXYGEN.order(BitOrder::LsbFirst));
+        let bits = lsb
+            .steps
+            .iter()
+            .map(|s| (s.bit, s.kept))
+            .collect::<Vec<_>>();
+        assert_eq!(bits, vec![(4, false), (3, true), (2, true), (1, true)]);
+        assert_eq!((lsb.rating, lsb.line), (BigUint::from(30u32), 2));
+    }
+
+    #[test]
+    fn parse_reports_ragged_line() {
+        let error = parse("0101\n011\n1100").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a number of 4 bits");
+        let error = parse("0101\n0121").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "2"));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::criteria::{BitOrder, Criteria, Elimination, Explanation};
+use common::{ParseError, Source};
+use num_bigint::BigUint;
+
+/// A diagnostic report of binary numbers of any width, packed into `u64`
+/// words and kept sorted. Numbers sharing a prefix then sit next to each
+/// other, and splitting them on their next bit is a binary search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    width: usize,
+    /// Words per number, the number being right aligned in them.
+    words: usize,
+    /// The numbers in ascending order, most significant word first.
+    packed: Vec<u64>,
+    /// The 0-based report line of every number.
+    lines: Vec<usize>,
+}
+
+impl Report {
+    pub fn parse(src: Source) -> Result<Self, ParseError> {
+        let width = src.text.lines().next().map_or(0, str::len);
+        if width == 0 {
+            return Err(src.error(src.text, "a binary number"));
+        }
+        let words = width.div_ceil(64);
+        let mut numbers = Vec::new();
+        for (index, line) in src.text.lines().enumerate() {
+            if line.len() != width {
+                return Err(src.error(line, format!("a number of {} bits", width)));
+            }
+            let mut number = vec![0u64; words];
+            // Pad on the left, so the words compare like the numbers do.
+            for (i, c) in line.char_indices() {
+                let bit = words * 64 - width + i;
+                match c {
+                    '0' => {}
+                    '1' => number[bit / 64] |= 1 << (63 - bit % 64),
+                    _ => return Err(src.error(&line[i..i + c.len_utf8()], "a bit")),
+                }
+            }
+            numbers.push((number, index));
+        }
+        numbers.sort_unstable();
+        let (numbers, lines): (Vec<_>, _) = numbers.into_iter().unzip();
+        Ok(Report {
+            width,
+            words,
+            packed: numbers.concat(),
+            lines,
+        })
+    }
+
+    /// Bits of every number.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Bit `bit` of number `index`, counting bits from the left.
+    pub fn bit(&self, index: usize, bit: usize) -> bool {
+        let bit = self.words * 64 - self.width + bit;
+        self.packed[index * self.words + bit / 64] >> (63 - bit % 64) & 1 == 1
+    }
+
+    pub fn number(&self, index: usize) -> BigUint {
+        self.packed[index * self.words..(index + 1) * self.words]
+            .iter()
+            .fold(BigUint::default(), |acc, &word| (acc << 64u32) + word)
+    }
+
+    /// How many numbers have a one at every bit position.
+    pub fn ones(&self) -> Vec<usize> {
+        let mut ones = vec![0; self.width];
+        let padding = self.words * 64 - self.width;
+        for (i, &word) in self.packed.iter().enumerate() {
+            let mut word = word;
+            while word != 0 {
+                let bit = (i % self.words) * 64 + 63 - word.trailing_zeros() as usize;
+                ones[bit - padding] += 1;
+                word &= word - 1;
+            }
+        }
+        ones
+    }
+
+    pub fn gamma_rate(&self) -> BigUint {
+        self.rate(Criteria::GAMMA)
+    }
+
+    pub fn epsilon_rate(&self) -> BigUint {
+        self.rate(Criteria::EPSILON)
+    }
+
+    /// The bit `criteria` picks out of the whole report at every position.
+    pub fn rate(&self, criteria: Criteria) -> BigUint {
+        let len = self.len();
+        self.ones()
+            .into_iter()
+            .fold(BigUint::default(), |acc, ones| {
+                let bit = criteria.pick(len - ones, ones);
+                (acc << 1u32) + BigUint::from(bit as u8)
+            })
+    }
+
+    pub fn o2_rating(&self) -> BigUint {
+        self.rating(Criteria::OXYGEN)
+    }
+
+    pub fn co2_rating(&self) -> BigUint {
+        self.rating(Criteria::CO2)
+    }
+
+    /// The number left after keeping, bit after bit, the numbers with the
+    /// bit `criteria` picks among them. When they all share the bit, they
+    /// are all kept.
+    pub fn rating(&self, criteria: Criteria) -> BigUint {
+        self.number(self.filter(criteria, None))
+    }
+
+    /// Like [`Report::rating`], also listing the lines every bit eliminates.
+    pub fn explain(&self, criteria: Criteria) -> Explanation {
+        let mut steps = Vec::new();
+        let index = self.filter(criteria, Some(&mut steps));
+        Explanation {
+            rating: self.number(index),
+            line: self.lines[index] + 1,
+            steps,
+        }
+    }
+
+    /// The index of the number `criteria` rates. Going from the most
+    /// significant bit, the candidates stay a range of the sorted numbers;
+    /// from the least significant one, they are listed one by one.
+    fn filter(&self, criteria: Criteria, mut steps: Option<&mut Vec<Elimination>>) -> usize {
+        let mut eliminate = |bit, zeros, ones, kept, removed: &mut dyn Iterator<Item = usize>| {
+            if let Some(steps) = steps.as_mut() {
+                let mut eliminated = removed.map(|i| self.lines[i] + 1).collect::<Vec<_>>();
+                eliminated.sort_unstable();
+                steps.push(Elimination {
+                    bit,
+                    zeros,
+                    ones,
+                    kept,
+                    eliminated,
+                });
+            }
+        };
+        match criteria.order {
+            BitOrder::MsbFirst => {
+                let (mut start, mut end) = (0, self.len());
+                for bit in 0..self.width {
+                    if end - start <= 1 {
+                        break;
+                    }
+                    let split = self.first_one(start, end, bit);
+                    let (zeros, ones) = (split - start, end - split);
+                    let kept = match (zeros, ones) {
+                        (0, _) => true,
+                        (_, 0) => false,
+                        _ => criteria.pick(zeros, ones),
+                    };
+                    let (keep, removed) = match kept {
+                        true => ((split, end), start..split),
+                        false => ((start, split), split..end),
+                    };
+                    eliminate(bit, zeros, ones, kept, &mut removed.into_iter());
+                    (start, end) = keep;
+                }
+                start
+            }
+            BitOrder::LsbFirst => {
+                let mut candidates = (0..self.len()).collect::<Vec<_>>();
+                for bit in (0..self.width).rev() {
+                    if candidates.len() <= 1 {
+                        break;
+                    }
+                    let ones = candidates.iter().filter(|&&i| self.bit(i, bit)).count();
+                    let zeros = candidates.len() - ones;
+                    let kept = match (zeros, ones) {
+                        (0, _) => true,
+                        (_, 0) => false,
+                        _ => criteria.pick(zeros, ones),
+                    };
+                    let (keep, removed) = candidates
+                        .into_iter()
+                        .partition::<Vec<_>, _>(|&i| self.bit(i, bit) == kept);
+                    eliminate(bit, zeros, ones, kept, &mut removed.into_iter());
+                    candidates = keep;
+                }
+                candidates[0]
+            }
+        }
+    }
+
+    /// The first of the numbers `start..end` with a one at `bit`. They share
+    /// every bit before it, so their zeros come first.
+    fn first_one(&self, start: usize, end: usize, bit: usize) -> usize {
+        let (mut low, mut high) = (start, end);
+        while low < high {
+            let middle = (low + high) / 2;
+            if self.bit(middle, bit) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        low
+    }
+}
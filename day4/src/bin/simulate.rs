@@ -0,0 +1,36 @@
+use clap::{builder::RangedU64ValueParser, Parser};
+use common::load;
+use day4::{parse, simulation, WinRules};
+use std::path::PathBuf;
+
+/// Plays random games on the boards of a puzzle input, drawing its numbers
+/// in shuffled orders, and reports the odds of every board
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Number of games to play
+    #[arg(
+        long,
+        default_value_t = 10_000,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    games: usize,
+    /// Seed of the shuffles, the same seed gives the same report
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+fn main() {
+    let args = Args::parse();
+    let (boards, numbers) = load(&args.input, parse);
+    let simulation = simulation::simulate(
+        &boards,
+        &numbers,
+        &WinRules::default(),
+        args.games,
+        args.seed,
+    );
+    print!("{}", simulation.report());
+}
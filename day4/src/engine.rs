@@ -0,0 +1,104 @@
+use crate::{compute_puzzle_result, Board, WinRules};
+use std::collections::{BTreeSet, HashMap};
+
+/// A board completing one of its patterns, on the draw at index `draw`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub draw: usize,
+    pub board: usize,
+    pub score: u64,
+}
+
+/// Plays bingo one draw at a time. Every number is indexed to the cells
+/// holding it and every pattern counts its unmarked cells, so a draw only
+/// touches the cells of the drawn number.
+pub struct Engine<'a> {
+    boards: &'a [Board],
+    /// The `(board, cell)` pairs holding each number, cells in row-major order.
+    cells: HashMap<u32, Vec<(usize, usize)>>,
+    /// For every board, the patterns through each of its cells.
+    patterns: Vec<Vec<Vec<usize>>>,
+    /// For every board, how many cells of each pattern are still unmarked.
+    unmarked: Vec<Vec<usize>>,
+    marked: Vec<Vec<bool>>,
+    won: Vec<bool>,
+    drawn: BTreeSet<u32>,
+    draws: usize,
+}
+
+impl<'a> Engine<'a> {
+    pub fn new(boards: &'a [Board], rules: &WinRules) -> Self {
+        let mut cells: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        let mut patterns = Vec::with_capacity(boards.len());
+        let mut unmarked = Vec::with_capacity(boards.len());
+        for (b, board) in boards.iter().enumerate() {
+            for (cell, &number) in board.data.iter().flatten().enumerate() {
+                cells.entry(number).or_default().push((b, cell));
+            }
+            let mut through = vec![Vec::new(); board.size * board.size];
+            let mut counts = Vec::new();
+            for (p, pattern) in rules.patterns(board.size).enumerate() {
+                for &(r, c) in &pattern {
+                    through[r * board.size + c].push(p);
+                }
+                counts.push(pattern.len());
+            }
+            patterns.push(through);
+            unmarked.push(counts);
+        }
+        Engine {
+            boards,
+            cells,
+            patterns,
+            unmarked,
+            marked: boards
+                .iter()
+                .map(|b| vec![false; b.size * b.size])
+                .collect(),
+            won: vec![false; boards.len()],
+            drawn: BTreeSet::new(),
+            draws: 0,
+        }
+    }
+
+    /// Marks `number` on every board and returns the boards it makes win,
+    /// by board index. Boards win once, later draws leave them out.
+    pub fn draw(&mut self, number: u32) -> Vec<Win> {
+        let draw = self.draws;
+        self.draws += 1;
+        self.drawn.insert(number);
+        let mut winners = Vec::new();
+        for &(b, cell) in self.cells.get(&number).into_iter().flatten() {
+            if std::mem::replace(&mut self.marked[b][cell], true) {
+                continue;
+            }
+            for &p in &self.patterns[b][cell] {
+                self.unmarked[b][p] -= 1;
+                if self.unmarked[b][p] == 0 && !self.won[b] {
+                    self.won[b] = true;
+                    winners.push(b);
+                }
+            }
+        }
+        winners.sort_unstable();
+        winners
+            .into_iter()
+            .map(|board| Win {
+                draw,
+                board,
+                score: compute_puzzle_result(&self.boards[board], &self.drawn, number),
+            })
+            .collect()
+    }
+}
+
+/// Every board winning while `draws` are drawn, in winning order. Boards
+/// winning on the same draw come by index.
+pub fn wins<'a>(
+    boards: &'a [Board],
+    draws: &'a [u32],
+    rules: &WinRules,
+) -> impl Iterator<Item = Win> + 'a {
+    let mut engine = Engine::new(boards, rules);
+    draws.iter().flat_map(move |&number| engine.draw(number))
+}
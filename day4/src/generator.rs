@@ -0,0 +1,28 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// A bingo subsystem with `size` boards of 5 by 5 distinct numbers. Every
+/// number is drawn, so every board wins eventually.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut numbers: Vec<usize> = (0..100.max(size * 5)).collect();
+    numbers.shuffle(&mut rng);
+    let mut blocks = vec![numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",")];
+    for _ in 0..size.max(1) {
+        let board = numbers
+            .choose_multiple(&mut rng, 25)
+            .map(|n| format!("{:>2}", n))
+            .collect::<Vec<_>>();
+        blocks.push(
+            board
+                .chunks(5)
+                .map(|row| row.join(" "))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+    blocks.join("\n\n")
+}
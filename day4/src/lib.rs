@@ -0,0 +1,324 @@
+mod engine;
+pub mod generator;
+mod rules;
+pub mod simulation;
+use common::{ParseError, Solution, Source};
+use std::{collections::BTreeSet, fmt, fs};
+
+pub use engine::{wins, Engine, Win};
+pub use rules::{WinRule, WinRules};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input<'a> = (Vec<Board>, Vec<u32>);
+    type Answer1 = Score;
+    type Answer2 = Score;
+
+    fn parse(input: &str) -> Result<(Vec<Board>, Vec<u32>), ParseError> {
+        parse(input)
+    }
+    fn part1((boards, lucky_numbers): &(Vec<Board>, Vec<u32>)) -> Score {
+        Score(puzzle_1(boards, lucky_numbers))
+    }
+    fn part2((boards, lucky_numbers): &(Vec<Board>, Vec<u32>)) -> Score {
+        Score(puzzle_2(boards, lucky_numbers))
+    }
+}
+
+/// The score of a winning board, shown as "no winner" when none wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score(pub Result<u64, NoWinner>);
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Ok(score) => write!(f, "{}", score),
+            Err(NoWinner) => write!(f, "{}", NoWinner),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Board>, Vec<u32>), ParseError> {
+    let src = Source::new(Day4::DAY, input);
+    let mut input_iter = input.split("\n\n");
+    let numbers_line = input_iter.next().unwrap_or_default().trim();
+    let lucky_numbers: Vec<u32> = numbers_line
+        .split(',')
+        .map(|s| src.parse(s, "a drawn number"))
+        .collect::<Result<_, _>>()?;
+
+    // Blank chunks, like extra blank lines at the end, hold no board.
+    let boards: Vec<Board> = input_iter
+        .filter(|b| !b.trim().is_empty())
+        .map(|b| Board::parse(src, b))
+        .collect::<Result<_, _>>()?;
+    Ok((boards, lucky_numbers))
+}
+
+pub fn load(path: &str) -> Result<(Vec<Board>, Vec<u32>), ParseError> {
+    parse(&fs::read_to_string(path).unwrap())
+}
+
+pub fn puzzle_1(boards: &[Board], lucky_numbers: &[u32]) -> Result<u64, NoWinner> {
+    first_winner_score(boards, lucky_numbers, &WinRules::default())
+}
+
+pub fn puzzle_2(boards: &[Board], lucky_numbers: &[u32]) -> Result<u64, NoWinner> {
+    last_winner_score(boards, lucky_numbers, &WinRules::default())
+}
+
+/// No board wins with the numbers drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoWinner;
+
+impl fmt::Display for NoWinner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no winner")
+    }
+}
+
+impl std::error::Error for NoWinner {}
+
+/// Score of the first board to win under `rules`.
+pub fn first_winner_score(
+    boards: &[Board],
+    lucky_numbers: &[u32],
+    rules: &WinRules,
+) -> Result<u64, NoWinner> {
+    wins(boards, lucky_numbers, rules)
+        .next()
+        .map(|win| win.score)
+        .ok_or(NoWinner)
+}
+
+/// Score of the last board to win under `rules`.
+pub fn last_winner_score(
+    boards: &[Board],
+    lucky_numbers: &[u32],
+    rules: &WinRules,
+) -> Result<u64, NoWinner> {
+    wins(boards, lucky_numbers, rules)
+        .last()
+        .map(|win| win.score)
+        .ok_or(NoWinner)
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    size: usize,
+    data: Vec<Vec<u32>>,
+}
+
+impl Board {
+    /// Parses the board at `input`, a slice of `src`.
+    pub fn parse(src: Source, input: &str) -> Result<Self, ParseError> {
+        let rows: Vec<&str> = input.trim().lines().collect();
+        let size = rows.len();
+        if size == 0 {
+            return Err(src.missing(input, "a board"));
+        }
+        let data: Vec<Vec<u32>> = rows
+            .iter()
+            .map(|l| {
+                let row = l
+                    .split_whitespace()
+                    .map(|s| src.parse(s, "a board number"))
+                    .collect::<Result<Vec<u32>, _>>()?;
+                if row.len() == size {
+                    Ok(row)
+                } else {
+                    Err(src.error(l, format!("a row of {} numbers", size)))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { data, size })
+    }
+}
+
+// fn get_punched_board(board: &Board, winning_numbers: &BTreeSet<u32>) -> Board {
+//     let mut b = board.clone();
+//     b.data = b
+//         .data
+//         .iter()
+//         .map(|arr| {
+//             arr.iter()
+//                 .map(|x| if winning_numbers.contains(x) { 0 } else { *x })
+//                 .collect()
+//         })
+//         .collect();
+//     b
+// }
+
+fn compute_puzzle_result(board: &Board, winning_numbers: &BTreeSet<u32>, last_number: u32) -> u64 {
+    board
+        .data
+        .iter()
+        .flatten()
+        .filter(|&i| !winning_numbers.contains(i))
+        .fold(0, |acc, n| acc + (*n as u64))
+        * last_number as u64
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn board(input: &str) -> Board {
+        Board::parse(Source::new(4, input), input).unwrap()
+    }
+
+    fn won(numbers: &[u32], rules: &WinRules) -> bool {
+        let boards = [board("1 2 3\n4 5 6\n7 8 9")];
+        let won = wins(&boards, numbers, rules).next().is_some();
+        won
+    }
+
+    #[test]
+    fn is_board_winning_horizontal() {
+        assert!(won(&[7, 8, 9], &WinRules::default()));
+    }
+
+    #[test]
+    fn is_board_winning_vertical() {
+        assert!(won(&[3, 6, 9], &WinRules::default()));
+    }
+
+    // By default, this bingo only needs horizontal and vertical
+    #[test]
+    fn is_board_winning_diagonal() {
+        assert!(!won(&[3, 5, 7], &WinRules::default()));
+    }
+
+    #[test]
+    fn is_board_winning_diagonal_2() {
+        assert!(!won(&[1, 5, 9], &WinRules::default()));
+    }
+
+    #[test]
+    fn other_win_rules() {
+        let only = |rule| WinRules(vec![rule]);
+        assert!(won(&[3, 5, 7], &only(WinRule::Diagonals)));
+        assert!(won(&[1, 5, 9], &only(WinRule::Diagonals)));
+        assert!(!won(&[7, 8, 9], &only(WinRule::Diagonals)));
+        assert!(won(&[1, 3, 7, 9], &only(WinRule::Corners)));
+        assert!(!won(&[1, 3, 7], &only(WinRule::Corners)));
+        assert!(!won(&[1, 2, 3, 4, 5, 6, 7, 8], &only(WinRule::Blackout)));
+        assert!(won(&[1, 2, 3, 4, 5, 6, 7, 8, 9], &only(WinRule::Blackout)));
+        let cross = WinRule::Mask(vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]);
+        assert!(won(&[2, 4, 5, 6, 8], &only(cross)));
+        assert!(!won(&[2, 4, 5, 6, 8], &only(WinRule::Mask(vec![(3, 3)]))));
+        let either = WinRules(vec![WinRule::Corners, WinRule::Rows]);
+        assert!(won(&[1, 2, 3], &either));
+    }
+
+    #[test]
+    fn first_winner_with_diagonals() {
+        let (boards, lucky_numbers) = load("test_case").unwrap();
+        let rules = WinRules(vec![WinRule::Rows, WinRule::Columns, WinRule::Diagonals]);
+        // The third board's other diagonal fills up on the 8th draw, a 2.
+        assert_eq!(
+            first_winner_score(&boards, &lucky_numbers, &rules),
+            Ok(247 * 2)
+        );
+        // A full card leaves nothing unmarked to score.
+        let blackout = WinRules(vec![WinRule::Blackout]);
+        assert_eq!(
+            first_winner_score(&boards, &lucky_numbers, &blackout),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn wins_in_order() {
+        let (boards, lucky_numbers) = load("test_case").unwrap();
+        let wins = wins(&boards, &lucky_numbers, &WinRules::default()).collect::<Vec<_>>();
+        assert_eq!(
+            wins,
+            vec![
+                Win {
+                    draw: 11,
+                    board: 2,
+                    score: 188 * 24
+                },
+                Win {
+                    draw: 13,
+                    board: 0,
+                    score: 137 * 16
+                },
+                Win {
+                    draw: 14,
+                    board: 1,
+                    score: 148 * 13
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn small_boards_win_from_the_first_draw() {
+        let (boards, lucky_numbers) = parse("1000,1,300,7\n\n300 1\n2 3\n\n7").unwrap();
+        assert_eq!(puzzle_1(&boards, &lucky_numbers), Ok(5 * 300));
+        assert_eq!(puzzle_2(&boards, &lucky_numbers), Ok(0));
+        let (boards, lucky_numbers) = parse("7\n\n7").unwrap();
+        assert_eq!(puzzle_1(&boards, &lucky_numbers), Ok(0));
+        let (boards, lucky_numbers) = parse("2,3\n\n1").unwrap();
+        assert_eq!(puzzle_1(&boards, &lucky_numbers), Err(NoWinner));
+    }
+
+    #[test]
+    fn parts_report_no_winner() {
+        let answers = common::solve::<Day4>("4,5\n\n1 2\n3 4", &[1, 2]).unwrap();
+        assert_eq!(answers[0].value, "no winner");
+        assert_eq!(answers[1].value, "no winner");
+        let answers = common::solve::<Day4>("4,1\n\n1", &[1]).unwrap();
+        assert_eq!(answers[0].value, "0");
+    }
+
+    #[test]
+    fn skip_blank_chunks() {
+        let (boards, _) = parse("1,2\n\n1 2\n2 1\n\n\n\n").unwrap();
+        assert_eq!(boards.len(), 1);
+        let rules = WinRules(vec![WinRule::Corners]);
+        assert_eq!(first_winner_score(&boards, &[1, 2], &rules), Ok(0));
+        let error = Board::parse(Source::new(4, " \n"), " \n").unwrap_err();
+        assert_eq!(error.expected, "a board");
+    }
+
+    #[test]
+    fn parse_reports_ragged_board() {
+        let error = parse("1,2,3\n\n1 2\n3 4\n\n5 6\n7\n").unwrap_err();
+        assert_eq!((error.line, error.column), (7, 1));
+        assert_eq!(error.expected, "a row of 2 numbers");
+    }
+
+    #[test]
+    fn parse_reports_bad_number() {
+        let error = parse("1,x,3\n\n1 2\n3 4\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 3, "x"));
+    }
+
+    #[test]
+    fn puzzle_1_work_correctly() {
+        let (boards, lucky_numbers) = load("test_case").unwrap();
+        assert_eq!(puzzle_1(&boards, &lucky_numbers), Ok(188 * 24))
+    }
+
+    #[test]
+    fn puzzle_2_work_correctly() {
+        let (boards, lucky_numbers) = load("test_case").unwrap();
+        assert_eq!(puzzle_2(&boards, &lucky_numbers), Ok(148 * 13))
+    }
+
+    #[test]
+    fn puzzle_1_answer() {
+        let (boards, lucky_numbers) = load("input").unwrap();
+        assert_eq!(puzzle_1(&boards, &lucky_numbers), Ok(74320))
+    }
+
+    #[test]
+    fn puzzle_2_answer() {
+        let (boards, lucky_numbers) = load("input").unwrap();
+        assert_eq!(puzzle_2(&boards, &lucky_numbers), Ok(17884))
+    }
+}
@@ -0,0 +1,60 @@
+/// A pattern of marked cells that wins a bingo board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WinRule {
+    Rows,
+    Columns,
+    /// Both diagonals, each one winning on its own.
+    Diagonals,
+    /// The four corners together.
+    Corners,
+    /// Every cell of the board.
+    Blackout,
+    /// Custom cells as `(row, column)`, which all need to be marked. A mask
+    /// reaching outside the board never wins on it.
+    Mask(Vec<(usize, usize)>),
+}
+
+impl WinRule {
+    /// The cells of every pattern the rule accepts on a `size` by `size`
+    /// board.
+    pub fn patterns(&self, size: usize) -> Vec<Vec<(usize, usize)>> {
+        let last = size.saturating_sub(1);
+        match self {
+            WinRule::Rows => (0..size)
+                .map(|r| (0..size).map(|c| (r, c)).collect())
+                .collect(),
+            WinRule::Columns => (0..size)
+                .map(|c| (0..size).map(|r| (r, c)).collect())
+                .collect(),
+            WinRule::Diagonals => vec![
+                (0..size).map(|i| (i, i)).collect(),
+                (0..size).map(|i| (i, last - i)).collect(),
+            ],
+            WinRule::Corners => vec![vec![(0, 0), (0, last), (last, 0), (last, last)]],
+            WinRule::Blackout => vec![(0..size)
+                .flat_map(|r| (0..size).map(move |c| (r, c)))
+                .collect()],
+            WinRule::Mask(cells) if cells.iter().all(|&(r, c)| r < size && c < size) => {
+                vec![cells.clone()]
+            }
+            WinRule::Mask(_) => Vec::new(),
+        }
+    }
+}
+
+/// The rules a game is played with, a board winning by any one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinRules(pub Vec<WinRule>);
+
+impl WinRules {
+    pub fn patterns(&self, size: usize) -> impl Iterator<Item = Vec<(usize, usize)>> + '_ {
+        self.0.iter().flat_map(move |rule| rule.patterns(size))
+    }
+}
+
+/// The puzzle's rules: a full row or a full column.
+impl Default for WinRules {
+    fn default() -> Self {
+        WinRules(vec![WinRule::Rows, WinRule::Columns])
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{wins, Board, WinRules};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use std::{collections::BTreeMap, fmt::Write};
+
+/// How one board fared over every simulated game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Odds {
+    /// Games won on the draw of the first win, shared by boards tying on it.
+    pub first: usize,
+    /// Games won on the draw of the last win, shared by boards tying on it.
+    pub last: usize,
+    /// Games won at all, the draws could run out before the board fills up.
+    pub wins: usize,
+    /// Draws taken to win, summed over the games won.
+    pub draws: usize,
+    /// How many games were won with each score.
+    pub scores: BTreeMap<u64, usize>,
+}
+
+impl Odds {
+    /// Average draws taken to win, `None` when the board never won.
+    pub fn expected_draws(&self) -> Option<f64> {
+        (self.wins > 0).then(|| self.draws as f64 / self.wins as f64)
+    }
+
+    /// The smallest score reached by at least `fraction` of the wins.
+    pub fn score_quantile(&self, fraction: f64) -> Option<u64> {
+        let rank = (fraction * self.wins as f64).ceil().max(1.0) as usize;
+        let mut seen = 0;
+        self.scores.iter().find_map(|(&score, &count)| {
+            seen += count;
+            (seen >= rank).then_some(score)
+        })
+    }
+}
+
+/// Outcome of `games` random games, one entry of `boards` per board.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    pub games: usize,
+    pub boards: Vec<Odds>,
+}
+
+impl Simulation {
+    pub fn first_probability(&self, board: usize) -> f64 {
+        self.boards[board].first as f64 / self.games as f64
+    }
+
+    pub fn last_probability(&self, board: usize) -> f64 {
+        self.boards[board].last as f64 / self.games as f64
+    }
+
+    /// A table with one row per board.
+    pub fn report(&self) -> String {
+        let mut out = String::from("board  first   last  draws  score min/median/max\n");
+        for (b, odds) in self.boards.iter().enumerate() {
+            let draws = odds
+                .expected_draws()
+                .map_or(String::from("-"), |d| format!("{:.1}", d));
+            let scores = match (odds.score_quantile(0.0), odds.score_quantile(0.5)) {
+                (Some(min), Some(median)) => {
+                    let max = odds.scores.keys().next_back().unwrap();
+                    format!("{}/{}/{}", min, median, max)
+                }
+                _ => String::from("-"),
+            };
+            writeln!(
+                out,
+                "{:>5} {:>5.1}% {:>5.1}% {:>6}  {}",
+                b,
+                100.0 * self.first_probability(b),
+                100.0 * self.last_probability(b),
+                draws,
+                scores
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+/// Plays `games` games on `boards`, each drawing every number of `pool` in
+/// an order shuffled by a generator seeded with `seed`. Panics when `games`
+/// is 0, which leaves no odds to report.
+pub fn simulate(
+    boards: &[Board],
+    pool: &[u32],
+    rules: &WinRules,
+    games: usize,
+    seed: u64,
+) -> Simulation {
+    assert!(games > 0, "a simulation plays at least one game");
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut odds = vec![Odds::default(); boards.len()];
+    let mut draws = pool.to_vec();
+    for _ in 0..games {
+        draws.shuffle(&mut rng);
+        let game = wins(boards, &draws, rules).collect::<Vec<_>>();
+        let (Some(first), Some(last)) = (game.first(), game.last()) else {
+            continue;
+        };
+        for win in &game {
+            let board = &mut odds[win.board];
+            board.wins += 1;
+            board.draws += win.draw + 1;
+            *board.scores.entry(win.score).or_default() += 1;
+            board.first += usize::from(win.draw == first.draw);
+            board.last += usize::from(win.draw == last.draw);
+        }
+    }
+    Simulation {
+        games,
+        boards: odds,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Source;
+
+    #[test]
+    fn odds_follow_the_boards() {
+        let input = "1 2\n3 4\n\n5 6\n7 8\n\n1 2\n3 4";
+        let src = Source::new(4, input);
+        let boards = input
+            .split("\n\n")
+            .map(|b| Board::parse(src, b))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let pool = [1, 2, 3, 4, 5, 6, 7, 8];
+        let simulation = simulate(&boards, &pool, &WinRules::default(), 500, 7);
+        assert_eq!(
+            simulation,
+            simulate(&boards, &pool, &WinRules::default(), 500, 7)
+        );
+        // Identical boards always tie, and every game has a first and last.
+        assert_eq!(simulation.boards[0], simulation.boards[2]);
+        for b in 0..3 {
+            assert_eq!(simulation.boards[b].wins, 500);
+            assert_eq!(simulation.boards[b].scores.values().sum::<usize>(), 500);
+        }
+        let first = simulation.first_probability(0) + simulation.first_probability(1);
+        assert!(first >= 1.0);
+        assert!((0.35..0.65).contains(&simulation.first_probability(1)));
+        let draws = simulation.boards[1].expected_draws().unwrap();
+        assert!((2.0..=6.0).contains(&draws));
+        assert_eq!(simulation.report().lines().count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "a simulation plays at least one game")]
+    fn reject_no_games() {
+        simulate(&[], &[1, 2], &WinRules::default(), 0, 7);
+    }
+}
@@ -0,0 +1,80 @@
+use clap::{Parser, ValueEnum};
+use common::load;
+use day5::{draw, heatmap::Image, parse, Orientation, Raster};
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Export {
+    /// Binary greyscale PGM
+    Pgm,
+    /// Greyscale PNG
+    Png,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Board {
+    /// Horizontal and vertical lines, as in part 1
+    Part1,
+    /// Diagonal lines too, as in part 2
+    Part2,
+    /// Both boards side by side
+    Both,
+}
+
+/// Renders the overlap board of the vent lines as a greyscale image, brighter
+/// where more lines overlap
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Image format, written to stdout
+    #[arg(long, value_enum, default_value_t = Export::Png)]
+    format: Export,
+    /// Board to render
+    #[arg(long, value_enum, default_value_t = Board::Both)]
+    board: Board,
+    /// Show points with at least this many overlaps in white
+    #[arg(long)]
+    highlight: Option<usize>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let lines = load(&args.input, parse);
+    let board = |diagonals: bool| {
+        let drawn = lines.iter().filter(|l| match l.orientation() {
+            Orientation::Horizontal | Orientation::Vertical => true,
+            Orientation::Diagonal => diagonals,
+            Orientation::Oblique { .. } => false,
+        });
+        draw(drawn, Raster::Lattice)
+    };
+    let boards = match args.board {
+        Board::Part1 => vec![board(false)],
+        Board::Part2 => vec![board(true)],
+        Board::Both => vec![board(false), board(true)],
+    };
+    // One scale for every board, so the same shade is the same count.
+    let max = boards.iter().map(Image::busiest).max().unwrap_or(0);
+    let image = boards
+        .iter()
+        .map(|board| Image::heatmap(board, max, args.highlight))
+        .reduce(|left, right| left.beside(&right, 8))
+        .unwrap();
+    let bytes = match args.format {
+        Export::Pgm => image.pgm(),
+        Export::Png => image.png().unwrap_or_else(|e| {
+            eprintln!("Fail to encode the image: {}", e);
+            process::exit(1)
+        }),
+    };
+    io::stdout().write_all(&bytes).unwrap_or_else(|e| {
+        eprintln!("Fail to write the image: {}", e);
+        process::exit(1)
+    });
+}
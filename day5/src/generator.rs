@@ -0,0 +1,27 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const SIDE: i64 = 1000;
+
+/// `size` hydrothermal vent lines in a 1000 by 1000 area, a third each
+/// horizontal, vertical and diagonal.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut lines = Vec::with_capacity(size);
+    while lines.len() < size.max(1) {
+        let (x1, y1) = (rng.gen_range(0..SIDE), rng.gen_range(0..SIDE));
+        let (dx, dy) = match rng.gen_range(0..3) {
+            0 => (if rng.gen() { 1 } else { -1 }, 0),
+            1 => (0, if rng.gen() { 1 } else { -1 }),
+            _ => (
+                if rng.gen() { 1 } else { -1 },
+                if rng.gen() { 1 } else { -1 },
+            ),
+        };
+        let length = rng.gen_range(1..300);
+        let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+        if (0..SIDE).contains(&x2) && (0..SIDE).contains(&y2) {
+            lines.push(format!("{},{} -> {},{}", x1, y1, x2, y2));
+        }
+    }
+    lines.join("\n")
+}
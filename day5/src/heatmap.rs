@@ -0,0 +1,102 @@
+use grid::DenseGrid;
+
+/// A greyscale picture, one byte per pixel, rows from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    /// The most overlaps on any point of `board`.
+    pub fn busiest(board: &DenseGrid<usize>) -> usize {
+        board.values().copied().max().unwrap_or(0)
+    }
+
+    /// Shades every point of `board` by its overlap count, black when empty
+    /// and white from `max` overlaps on, so images sharing `max` share their
+    /// shades. With `highlight`, points with at least that many overlaps are
+    /// white and every other point at most mid grey.
+    pub fn heatmap(board: &DenseGrid<usize>, max: usize, highlight: Option<usize>) -> Self {
+        let max = max.max(1);
+        let pixels = board
+            .values()
+            .map(|&count| match highlight {
+                Some(threshold) if count >= threshold => 255,
+                Some(_) => (count.min(max) * 127 / max) as u8,
+                None => (count.min(max) * 255 / max) as u8,
+            })
+            .collect();
+        Image {
+            width: board.width(),
+            height: board.height(),
+            pixels,
+        }
+    }
+
+    /// `self` on the left of `other`, `gap` black columns apart. The shorter
+    /// one is padded with black at the bottom.
+    pub fn beside(&self, other: &Image, gap: usize) -> Self {
+        let width = self.width + gap + other.width;
+        let height = self.height.max(other.height);
+        let mut pixels = vec![0; width * height];
+        for (image, left) in [(self, 0), (other, self.width + gap)] {
+            for (y, row) in image.pixels.chunks(image.width.max(1)).enumerate() {
+                let start = y * width + left;
+                pixels[start..start + row.len()].copy_from_slice(row);
+            }
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// As a binary PGM file.
+    pub fn pgm(&self) -> Vec<u8> {
+        let mut out = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend_from_slice(&self.pixels);
+        out
+    }
+
+    /// As a PNG file.
+    pub fn png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{draw, parse, Raster};
+
+    #[test]
+    fn shade_and_highlight() {
+        let lines = parse("0,0 -> 2,0\n1,0 -> 1,1").unwrap();
+        let board = draw(&lines, Raster::Lattice);
+        assert_eq!(Image::busiest(&board), 2);
+        let image = Image::heatmap(&board, 2, None);
+        assert_eq!((image.width, image.height), (3, 2));
+        assert_eq!(image.pixels, vec![127, 255, 127, 0, 127, 0]);
+        let highlighted = Image::heatmap(&board, 2, Some(2));
+        assert_eq!(highlighted.pixels, vec![63, 255, 63, 0, 63, 0]);
+        // Shared with a busier board, the same counts get darker.
+        let shared = Image::heatmap(&board, 4, None);
+        assert_eq!(shared.pixels, vec![63, 127, 63, 0, 63, 0]);
+
+        let pair = image.beside(&Image::heatmap(&board, 2, Some(1)), 1);
+        assert_eq!((pair.width, pair.height), (7, 2));
+        assert_eq!(&pair.pixels[..7], &[127, 255, 127, 0, 255, 255, 255]);
+        assert!(pair.pgm().starts_with(b"P5\n7 2\n255\n"));
+        assert!(pair.png().unwrap().starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+}
@@ -0,0 +1,282 @@
+pub mod generator;
+pub mod heatmap;
+mod overlap;
+use common::{ParseError, Solution, Source};
+use grid::{DenseGrid, Point};
+use std::fs;
+
+pub use overlap::count_overlaps;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input<'a> = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &Vec<Line>) -> usize {
+        count_overlaps(
+            input
+                .iter()
+                .filter(|l| matches!(l.orientation(), Horizontal | Vertical)),
+        )
+    }
+    fn part2(input: &Vec<Line>) -> usize {
+        count_overlaps(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    let src = Source::new(Day5::DAY, input);
+    input.lines().map(|l| Line::parse(src, l)).collect()
+}
+
+pub fn load(path: &str) -> Result<Vec<Line>, ParseError> {
+    parse(&fs::read_to_string(path).unwrap())
+}
+
+/// Counts overlaps on a board as large as the coordinates, the reference for
+/// [`count_overlaps`].
+pub fn puzzle_1(lines: &[Line]) -> usize {
+    let straight = lines
+        .iter()
+        .filter(|l| matches!(l.orientation(), Horizontal | Vertical));
+    count_drawn(straight, Raster::Lattice)
+}
+
+pub fn puzzle_2(lines: &[Line]) -> usize {
+    let regular = lines
+        .iter()
+        .filter(|l| matches!(l.orientation(), Horizontal | Vertical | Diagonal));
+    count_drawn(regular, Raster::Lattice)
+}
+
+/// Counts the points covered by at least two of `lines`, drawn with `raster`
+/// on a board as large as the coordinates.
+pub fn count_drawn<'a>(lines: impl IntoIterator<Item = &'a Line>, raster: Raster) -> usize {
+    draw(lines, raster).values().filter(|&&x| x >= 2).count()
+}
+
+/// A board as large as the coordinates of `lines`, counting the lines
+/// covering every point.
+pub fn draw<'a>(lines: impl IntoIterator<Item = &'a Line>, raster: Raster) -> DenseGrid<usize> {
+    let lines: Vec<&Line> = lines.into_iter().collect();
+    let mut board = create_board(&lines);
+    for line in lines {
+        for point in line.points(raster) {
+            board[point] += 1;
+        }
+    }
+    board
+}
+
+fn create_board(lines: &[&Line]) -> DenseGrid<usize> {
+    let (max_x, max_y) = lines.iter().fold((0, 0), |(x, y), line| {
+        (
+            x.max(line.from.x).max(line.to.x),
+            y.max(line.from.y).max(line.to.y),
+        )
+    });
+    DenseGrid::new(max_x as usize + 1, max_y as usize + 1, 0)
+}
+
+#[derive(Debug)]
+pub struct Line {
+    from: Point,
+    to: Point,
+}
+
+/// The way a line runs across the floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Along `x`, single points included.
+    Horizontal,
+    /// Along `y`.
+    Vertical,
+    /// At 45 degrees, either way.
+    Diagonal,
+    /// Any other slope, `rise` over `run` in lowest terms with `run > 0`.
+    Oblique { run: i64, rise: i64 },
+}
+
+use Orientation::*;
+
+/// How a line is turned into grid points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Raster {
+    /// Only the lattice points the line passes through exactly, stepping by
+    /// the gcd-reduced slope.
+    Lattice,
+    /// One point per step along the longer axis, Bresenham's nearest cells.
+    Bresenham,
+}
+
+fn parse_point(src: Source, string: &str) -> Result<Point, ParseError> {
+    let mut iter = string.split(',');
+    let x = src.next::<u32>(&mut iter, string, "an x coordinate")?;
+    let y = src.next::<u32>(&mut iter, string, "a y coordinate")?;
+    Ok(Point::new(x as i64, y as i64))
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl Line {
+    fn parse(src: Source, line: &str) -> Result<Self, ParseError> {
+        let (from, to) = line
+            .split_once(" -> ")
+            .ok_or_else(|| src.error(line, "a line `x1,y1 -> x2,y2`"))?;
+        Ok(Self {
+            from: parse_point(src, from)?,
+            to: parse_point(src, to)?,
+        })
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        let (dx, dy) = (self.to.x - self.from.x, self.to.y - self.from.y);
+        if dy == 0 {
+            Horizontal
+        } else if dx == 0 {
+            Vertical
+        } else if dx.abs() == dy.abs() {
+            Diagonal
+        } else {
+            let g = gcd(dx, dy) * dx.signum();
+            Oblique {
+                run: dx / g,
+                rise: dy / g,
+            }
+        }
+    }
+
+    /// The points of the line from `from` to `to`, both included.
+    pub fn points(&self, raster: Raster) -> Points {
+        let (dx, dy) = (self.to.x - self.from.x, self.to.y - self.from.y);
+        let g = gcd(dx, dy).max(1);
+        let (step, delta) = match raster {
+            Raster::Lattice => (Point::new(dx / g, dy / g), Point::default()),
+            Raster::Bresenham => (
+                Point::new(dx.signum(), dy.signum()),
+                Point::new(dx.abs(), -dy.abs()),
+            ),
+        };
+        Points {
+            raster,
+            next: Some(self.from),
+            to: self.to,
+            step,
+            delta,
+            error: delta.x + delta.y,
+        }
+    }
+}
+
+/// Iterator over the points of a [`Line`].
+pub struct Points {
+    raster: Raster,
+    next: Option<Point>,
+    to: Point,
+    step: Point,
+    /// Bresenham's `(|dx|, -|dy|)`, and the error term between both axes.
+    delta: Point,
+    error: i64,
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        let point = self.next?;
+        self.next = (point != self.to).then(|| match self.raster {
+            Raster::Lattice => point + self.step,
+            Raster::Bresenham => {
+                let mut next = point;
+                let twice = 2 * self.error;
+                if twice >= self.delta.y {
+                    self.error += self.delta.y;
+                    next.x += self.step.x;
+                }
+                if twice <= self.delta.x {
+                    self.error += self.delta.x;
+                    next.y += self.step.y;
+                }
+                next
+            }
+        });
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn puzzle_1_working() {
+        let lines = load("test_case").unwrap();
+        let result = puzzle_1(&lines);
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn parse_reports_missing_coordinate() {
+        let error = parse("0,9 -> 5,9\n8,0 -> 0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        assert_eq!(error.expected, "a y coordinate");
+    }
+
+    #[test]
+    fn classify_lines() {
+        let lines = parse("0,9 -> 5,9\n3,3 -> 3,3\n7,0 -> 7,4\n8,0 -> 0,8\n6,4 -> 0,0").unwrap();
+        let orientations = lines.iter().map(Line::orientation).collect::<Vec<_>>();
+        assert_eq!(
+            orientations,
+            vec![
+                Horizontal,
+                Horizontal,
+                Vertical,
+                Diagonal,
+                Oblique { run: 3, rise: 2 }
+            ]
+        );
+    }
+
+    #[test]
+    fn rasterize_any_slope() {
+        let lines = parse("0,0 -> 6,4\n0,4 -> 6,0\n1,0 -> 2,7").unwrap();
+        let lattice = lines[0].points(Raster::Lattice).collect::<Vec<_>>();
+        let expected = [(0, 0), (3, 2), (6, 4)].map(|(x, y)| Point::new(x, y));
+        assert_eq!(lattice, expected);
+        assert_eq!(count_drawn(&lines, Raster::Lattice), 1);
+        for line in &lines {
+            let points = line.points(Raster::Bresenham).collect::<Vec<_>>();
+            let (dx, dy) = (line.to.x - line.from.x, line.to.y - line.from.y);
+            assert_eq!(points.len() as i64, dx.abs().max(dy.abs()) + 1);
+            assert_eq!((points[0], points[points.len() - 1]), (line.from, line.to));
+            assert!(points
+                .windows(2)
+                .all(|w| (w[1].x - w[0].x).abs() <= 1 && (w[1].y - w[0].y).abs() <= 1));
+        }
+        let straight = parse("0,9 -> 5,9\n8,0 -> 0,8").unwrap();
+        for line in &straight {
+            assert!(line
+                .points(Raster::Lattice)
+                .eq(line.points(Raster::Bresenham)));
+        }
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let lines = load("test_case").unwrap();
+        let result = puzzle_2(&lines);
+        assert_eq!(result, 12);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{Line, Orientation};
+use grid::Point;
+use std::collections::{HashMap, HashSet};
+
+/// The directions a vent line can run in. Points of a direction are keyed by
+/// the carrier they lie on and placed along it by a position `t`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Direction {
+    /// Along `x`, carriers keyed by `y`.
+    Row,
+    /// Along `y`, carriers keyed by `x`.
+    Column,
+    /// Along `x = y`, carriers keyed by `x - y`.
+    Diagonal,
+    /// Along `x = -y`, carriers keyed by `x + y`.
+    AntiDiagonal,
+}
+
+use Direction::*;
+
+impl Direction {
+    const ALL: [Direction; 4] = [Row, Column, Diagonal, AntiDiagonal];
+
+    fn of(line: &Line) -> Option<Self> {
+        match line.orientation() {
+            Orientation::Horizontal => Some(Row),
+            Orientation::Vertical => Some(Column),
+            Orientation::Diagonal if line.to.x - line.from.x == line.to.y - line.from.y => {
+                Some(Diagonal)
+            }
+            Orientation::Diagonal => Some(AntiDiagonal),
+            Orientation::Oblique { .. } => None,
+        }
+    }
+
+    fn key(self, p: Point) -> i64 {
+        match self {
+            Row => p.y,
+            Column => p.x,
+            Diagonal => p.x - p.y,
+            AntiDiagonal => p.x + p.y,
+        }
+    }
+
+    fn t(self, p: Point) -> i64 {
+        match self {
+            Column => p.y,
+            _ => p.x,
+        }
+    }
+
+    fn point(self, key: i64, t: i64) -> Point {
+        match self {
+            Row => Point::new(t, key),
+            Column => Point::new(key, t),
+            Diagonal => Point::new(t, t - key),
+            AntiDiagonal => Point::new(t, key - t),
+        }
+    }
+}
+
+/// Inclusive `(lo, hi)` stretches of a carrier.
+type Stretches = Vec<(i64, i64)>;
+
+/// A stretch `lo..=hi` of a carrier.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    direction: Direction,
+    key: i64,
+    lo: i64,
+    hi: i64,
+}
+
+impl Span {
+    fn contains(&self, p: Point) -> bool {
+        self.direction.key(p) == self.key && (self.lo..=self.hi).contains(&self.direction.t(p))
+    }
+
+    /// The `x` range of the span, to skip pairs far apart.
+    fn xs(&self) -> (i64, i64) {
+        let (a, b) = (
+            self.direction.point(self.key, self.lo).x,
+            self.direction.point(self.key, self.hi).x,
+        );
+        (a.min(b), a.max(b))
+    }
+
+    /// The grid point where two spans of different directions cross, if any.
+    fn crossing(&self, other: &Span) -> Option<Point> {
+        // `other`'s key moves linearly along `self`, find where it matches.
+        let start = other.direction.key(self.direction.point(self.key, 0));
+        let slope = other.direction.key(self.direction.point(self.key, 1)) - start;
+        let offset = other.key - start;
+        if slope == 0 || offset % slope != 0 {
+            return None;
+        }
+        let p = self.direction.point(self.key, offset / slope);
+        (self.contains(p) && other.contains(p)).then_some(p)
+    }
+}
+
+/// Counts the points covered by at least two of `lines`, in time and memory
+/// depending on the number of lines rather than on their coordinates.
+/// Collinear lines are merged by sweeping along their carrier, crossings of
+/// different carriers are found pairwise. Oblique lines are ignored,
+/// [`count_drawn`](crate::count_drawn) rasterizes them.
+pub fn count_overlaps<'a>(lines: impl IntoIterator<Item = &'a Line>) -> usize {
+    let mut carriers: HashMap<(Direction, i64), Stretches> = HashMap::new();
+    for line in lines {
+        if let Some(direction) = Direction::of(line) {
+            let (a, b) = (direction.t(line.from), direction.t(line.to));
+            carriers
+                .entry((direction, direction.key(line.from)))
+                .or_default()
+                .push((a.min(b), a.max(b)));
+        }
+    }
+
+    let mut covered = Vec::new();
+    let mut overlapped: HashMap<(Direction, i64), Stretches> = HashMap::new();
+    for (&(direction, key), spans) in &carriers {
+        let (once, twice) = sweep(spans);
+        covered.extend(once.into_iter().map(|(lo, hi)| Span {
+            direction,
+            key,
+            lo,
+            hi,
+        }));
+        if !twice.is_empty() {
+            overlapped.insert((direction, key), twice);
+        }
+    }
+    // How many of the stretches counted as collinear overlaps hold `p`.
+    let overlaps = |p: Point| {
+        Direction::ALL
+            .iter()
+            .filter(|&&d| {
+                overlapped.get(&(d, d.key(p))).is_some_and(|spans| {
+                    let t = d.t(p);
+                    let i = spans.partition_point(|&(_, hi)| hi < t);
+                    spans.get(i).is_some_and(|&(lo, _)| lo <= t)
+                })
+            })
+            .count() as i64
+    };
+
+    covered.sort_unstable_by_key(|span| span.xs());
+    let mut crossings = HashSet::new();
+    for (i, a) in covered.iter().enumerate() {
+        let (_, right) = a.xs();
+        for b in covered[i + 1..].iter().take_while(|b| b.xs().0 <= right) {
+            if a.direction != b.direction {
+                crossings.extend(a.crossing(b));
+            }
+        }
+    }
+
+    let collinear: i64 = overlapped
+        .values()
+        .flatten()
+        .map(|&(lo, hi)| hi - lo + 1)
+        .sum();
+    // Every crossing counts once, whether or not it already did as an
+    // overlap, or as several where overlaps of different carriers cross.
+    let adjust: i64 = crossings.into_iter().map(|p| 1 - overlaps(p)).sum();
+    (collinear + adjust) as usize
+}
+
+/// Merges the inclusive `spans` of one carrier into the stretches covered at
+/// least once and at least twice, both sorted.
+fn sweep(spans: &[(i64, i64)]) -> (Stretches, Stretches) {
+    let mut events: Vec<(i64, i32)> = spans
+        .iter()
+        .flat_map(|&(lo, hi)| [(lo, 1), (hi + 1, -1)])
+        .collect();
+    events.sort_unstable();
+    let (mut once, mut twice) = (Vec::new(), Vec::new());
+    let mut depth = 0;
+    let (mut once_start, mut twice_start) = (0, 0);
+    for (t, change) in events {
+        let before = depth;
+        depth += change;
+        match (before, depth) {
+            (0, 1) => once_start = t,
+            (1, 0) => once.push((once_start, t - 1)),
+            (1, 2) => twice_start = t,
+            (2, 1) => twice.push((twice_start, t - 1)),
+            _ => {}
+        }
+    }
+    (once, twice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generator, parse, puzzle_1, puzzle_2, Orientation::*};
+    use std::fs;
+
+    #[test]
+    fn matches_the_dense_board() {
+        let inputs = (0..5).map(|seed| generator::generate(200, seed));
+        for input in inputs.chain([fs::read_to_string("input").unwrap()]) {
+            let lines = parse(&input).unwrap();
+            let straight = lines
+                .iter()
+                .filter(|l| matches!(l.orientation(), Horizontal | Vertical));
+            assert_eq!(count_overlaps(straight), puzzle_1(&lines));
+            assert_eq!(count_overlaps(&lines), puzzle_2(&lines));
+        }
+    }
+
+    #[test]
+    fn huge_coordinates() {
+        let lines = parse(
+            "0,0 -> 4000000000,0\n\
+             2000000000,5 -> 2000000000,0\n\
+             0,0 -> 3000000000,0\n\
+             1,1 -> 6,6\n\
+             6,0 -> 0,6\n\
+             0,6 -> 2,6\n\
+             2000000000,3 -> 2000000000,0",
+        )
+        .unwrap();
+        // Rows overlap on 0..=3e9 and columns on 0..=3, both holding
+        // (2e9, 0). The diagonals cross at (3, 3), the short row ends on one
+        // at (0, 6).
+        assert_eq!(count_overlaps(&lines), 3_000_000_001 + 3 + 2);
+    }
+}
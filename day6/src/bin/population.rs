@@ -0,0 +1,46 @@
+use clap::{value_parser, Parser};
+use common::{load, Solution};
+use day6::{population, Day6, Exact, LifeCycle};
+use std::path::PathBuf;
+
+/// Prints the lanternfish by timer value every day, as CSV
+#[derive(Parser)]
+struct Args {
+    /// Puzzle input, `-` for stdin
+    #[arg(default_value = "input")]
+    input: PathBuf,
+    /// Days to follow the school for
+    #[arg(long, default_value_t = 256)]
+    days: usize,
+    /// Days between two births of the same fish
+    #[arg(
+        long,
+        default_value_t = LifeCycle::LANTERNFISH.spawn_interval,
+        value_parser = value_parser!(u8).range(1..)
+    )]
+    spawn_interval: u8,
+    /// Days before a newborn gives birth for the first time
+    #[arg(
+        long,
+        default_value_t = LifeCycle::LANTERNFISH.newborn_delay,
+        value_parser = value_parser!(u8).range(1..)
+    )]
+    newborn_delay: u8,
+    /// Days a fish lives, forever if not given
+    #[arg(long, value_parser = value_parser!(u32).range(1..))]
+    lifespan: Option<u32>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let timers = load(&args.input, Day6::parse);
+    let cycle = LifeCycle {
+        spawn_interval: args.spawn_interval,
+        newborn_delay: args.newborn_delay,
+        lifespan: args.lifespan,
+    };
+    print!(
+        "{}",
+        population(&timers, args.days, &cycle, &Exact).csv(&Exact)
+    );
+}
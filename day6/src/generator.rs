@@ -0,0 +1,10 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A school of `size` lanternfish with timers between 1 and 5.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| rng.gen_range(1..=5).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,79 @@
+pub mod generator;
+mod lifecycle;
+mod matrix;
+use common::{ParseError, Solution, Source};
+use num_bigint::BigUint;
+
+pub use lifecycle::{population, LifeCycle, Series};
+pub use matrix::{count_fish, Arithmetic, Exact, Modulo};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input<'a> = Vec<u8>;
+    type Answer1 = BigUint;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+        parse_initial_fish_count(input)
+    }
+    fn part1(fish_counter: &Vec<u8>) -> BigUint {
+        count_fish(fish_counter, 80, &LifeCycle::LANTERNFISH, &Exact)
+    }
+    fn part2(fish_counter: &Vec<u8>) -> BigUint {
+        count_fish(fish_counter, 256, &LifeCycle::LANTERNFISH, &Exact)
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    count_fish_after(&Day6::parse(input).unwrap(), 80, 7)
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    count_fish_after(&Day6::parse(input).unwrap(), 256, 7)
+}
+
+fn parse_initial_fish_count(input: &str) -> Result<Vec<u8>, ParseError> {
+    let src = Source::new(Day6::DAY, input);
+    input
+        .trim()
+        .split(',')
+        .map(|s| src.parse(s, "a fish timer"))
+        .collect()
+}
+
+/// Simulates the fish day by day, the reference for [`count_fish`].
+pub fn count_fish_after(init_state: &[u8], day: usize, day_to_mature: u8) -> usize {
+    let mut dtm_counter = (0..=day_to_mature + 1)
+        .map(|x| init_state.iter().filter(|&&u| u == x).count())
+        .collect::<Vec<usize>>();
+    (0..day).for_each(|_| {
+        dtm_counter[day_to_mature as usize] += dtm_counter[0];
+        dtm_counter.rotate_left(1);
+    });
+    dtm_counter.iter().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn puzzle_1_working() {
+        let input = "3,4,3,1,2";
+        assert_eq!(puzzle_1(input), 5934);
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = "3,4,3,1,2";
+        assert_eq!(puzzle_2(input), 26984457539);
+    }
+
+    #[test]
+    fn parts_use_the_matrix() {
+        let school = Day6::parse("3,4,3,1,2").unwrap();
+        assert_eq!(Day6::part1(&school), BigUint::from(5934u32));
+        assert_eq!(Day6::part2(&school), BigUint::from(26984457539u64));
+    }
+}
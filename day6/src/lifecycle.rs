@@ -0,0 +1,190 @@
+use crate::Arithmetic;
+use std::fmt::{Display, Write};
+
+/// How lanternfish live, in days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LifeCycle {
+    /// Days between two births of the same fish.
+    pub spawn_interval: u8,
+    /// Days before a newborn gives birth for the first time.
+    pub newborn_delay: u8,
+    /// Days a fish lives, at least one, those of the initial school counting
+    /// from day 0. Fish still give birth on their last day. Tracking ages
+    /// multiplies the states by the lifespan.
+    pub lifespan: Option<u32>,
+}
+
+impl LifeCycle {
+    /// The puzzle's fish, giving birth every 7 days from their 9th on.
+    pub const LANTERNFISH: LifeCycle = LifeCycle {
+        spawn_interval: 7,
+        newborn_delay: 9,
+        lifespan: None,
+    };
+
+    /// The states a school of fish starting with `timers` can be in, as the
+    /// number of ages tracked and of timer values.
+    pub(crate) fn shape(&self, timers: &[u8]) -> (usize, usize) {
+        assert!(
+            self.spawn_interval > 0 && self.newborn_delay > 0,
+            "fish need at least a day between births"
+        );
+        assert!(self.lifespan != Some(0), "fish need to live at least a day");
+        let ages = self.lifespan.map_or(1, |days| days as usize);
+        let max_timer = timers.iter().copied().max().unwrap_or(0);
+        let values =
+            (self.spawn_interval.max(self.newborn_delay) as usize).max(max_timer as usize + 1);
+        (ages, values)
+    }
+
+    /// The states the fish in `state` are in a day later, one for them, if
+    /// still alive, and one for their newborn. States number `age * values
+    /// + timer`, ages only counting with a lifespan.
+    pub(crate) fn successors(&self, (ages, values): (usize, usize), state: usize) -> Vec<usize> {
+        let (age, timer) = (state / values, state % values);
+        let next_age = if self.lifespan.is_some() { age + 1 } else { 0 };
+        let mut next = Vec::with_capacity(2);
+        if next_age < ages {
+            let next_timer = match timer {
+                0 => self.spawn_interval as usize - 1,
+                _ => timer - 1,
+            };
+            next.push(next_age * values + next_timer);
+        }
+        if timer == 0 {
+            next.push(self.newborn_delay as usize - 1);
+        }
+        next
+    }
+}
+
+/// The fish by timer value, every day from day 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Series<T> {
+    pub days: Vec<Vec<T>>,
+}
+
+impl<T: Display> Series<T> {
+    /// One row per day and one column per timer value, then the total.
+    pub fn csv<A: Arithmetic<Value = T>>(&self, arithmetic: &A) -> String {
+        let values = self.days.first().map_or(0, Vec::len);
+        let mut out = String::from("day");
+        for timer in 0..values {
+            write!(out, ",timer{}", timer).unwrap();
+        }
+        out.push_str(",total\n");
+        for (day, counts) in self.days.iter().enumerate() {
+            write!(out, "{}", day).unwrap();
+            for count in counts {
+                write!(out, ",{}", count).unwrap();
+            }
+            let total = counts
+                .iter()
+                .fold(arithmetic.count(0), |sum, c| arithmetic.add(&sum, c));
+            writeln!(out, ",{}", total).unwrap();
+        }
+        out
+    }
+}
+
+/// Follows the school starting with `timers` for `days`, recording how many
+/// fish have every timer value each day.
+pub fn population<A: Arithmetic>(
+    timers: &[u8],
+    days: usize,
+    cycle: &LifeCycle,
+    arithmetic: &A,
+) -> Series<A::Value> {
+    let shape @ (ages, values) = cycle.shape(timers);
+    let mut state = vec![arithmetic.count(0); ages * values];
+    for &timer in timers {
+        let fish = &mut state[timer as usize];
+        *fish = arithmetic.add(fish, &arithmetic.count(1));
+    }
+    let by_timer = |state: &[A::Value]| {
+        (0..values)
+            .map(|timer| {
+                (0..ages).fold(arithmetic.count(0), |sum, age| {
+                    arithmetic.add(&sum, &state[age * values + timer])
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut series = vec![by_timer(&state)];
+    for _ in 0..days {
+        let mut next = vec![arithmetic.count(0); state.len()];
+        for (from, fish) in state.iter().enumerate() {
+            for to in cycle.successors(shape, from) {
+                next[to] = arithmetic.add(&next[to], fish);
+            }
+        }
+        state = next;
+        series.push(by_timer(&state));
+    }
+    Series { days: series }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Exact, Modulo};
+
+    // The counts are small enough for `Modulo::new(u64::MAX)` to never wrap.
+    fn totals(series: &Series<u64>) -> Vec<u64> {
+        series.days.iter().map(|day| day.iter().sum()).collect()
+    }
+
+    #[test]
+    fn puzzle_example_series() {
+        let series = population(
+            &[3, 4, 3, 1, 2],
+            18,
+            &LifeCycle::LANTERNFISH,
+            &Modulo::new(u64::MAX),
+        );
+        assert_eq!(series.days[1], vec![1, 1, 2, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(totals(&series)[18], 26);
+        let csv = population(&[1], 2, &LifeCycle::LANTERNFISH, &Exact).csv(&Exact);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "day,timer0,timer1,timer2,timer3,timer4,timer5,timer6,timer7,timer8,total"
+        );
+        assert_eq!(lines[3], "2,0,0,0,0,0,0,1,0,1,2");
+    }
+
+    #[test]
+    fn other_life_cycles() {
+        // Timers past the newborn delay are kept, not dropped.
+        let series = population(&[12], 13, &LifeCycle::LANTERNFISH, &Modulo::new(u64::MAX));
+        assert_eq!(series.days[0].len(), 13);
+        assert_eq!(totals(&series), [vec![1; 13], vec![2]].concat());
+
+        // Every 2 days, newborns waiting 3: 1, 1, 2, 2, 3, 4, ...
+        let quick = LifeCycle {
+            spawn_interval: 2,
+            newborn_delay: 3,
+            lifespan: None,
+        };
+        let series = population(&[0], 6, &quick, &Modulo::new(u64::MAX));
+        assert_eq!(totals(&series), vec![1, 2, 2, 3, 4, 5, 7]);
+
+        // Living 3 days, a fish gives birth once and dies.
+        let mortal = LifeCycle {
+            lifespan: Some(3),
+            ..quick
+        };
+        let series = population(&[0], 6, &mortal, &Modulo::new(u64::MAX));
+        assert_eq!(totals(&series), vec![1, 2, 2, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fish need to live at least a day")]
+    fn reject_no_lifespan() {
+        let stillborn = LifeCycle {
+            lifespan: Some(0),
+            ..LifeCycle::LANTERNFISH
+        };
+        population(&[3], 2, &stillborn, &Exact);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::LifeCycle;
+use num_bigint::BigUint;
+
+/// The numbers populations are counted with.
+pub trait Arithmetic {
+    type Value: Clone;
+
+    fn count(&self, count: u64) -> Self::Value;
+    fn add(&self, a: &Self::Value, b: &Self::Value) -> Self::Value;
+    fn mul(&self, a: &Self::Value, b: &Self::Value) -> Self::Value;
+}
+
+/// Exact counts. They grow by about one bit every eight days, so this suits
+/// up to a few million days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exact;
+
+impl Arithmetic for Exact {
+    type Value = BigUint;
+
+    fn count(&self, count: u64) -> BigUint {
+        BigUint::from(count)
+    }
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a + b
+    }
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a * b
+    }
+}
+
+/// Counts modulo a number, for any number of days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modulo(u64);
+
+impl Modulo {
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "counts need a modulus of at least 1");
+        Modulo(modulus)
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Arithmetic for Modulo {
+    type Value = u64;
+
+    fn count(&self, count: u64) -> u64 {
+        count % self.0
+    }
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 + *b as u128) % self.0 as u128) as u64
+    }
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        (*a as u128 * *b as u128 % self.0 as u128) as u64
+    }
+}
+
+type Matrix<T> = Vec<Vec<T>>;
+
+fn product<A: Arithmetic>(
+    arithmetic: &A,
+    a: &Matrix<A::Value>,
+    b: &Matrix<A::Value>,
+) -> Matrix<A::Value> {
+    let n = a.len();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    (0..n).fold(arithmetic.count(0), |sum, k| {
+                        arithmetic.add(&sum, &arithmetic.mul(&a[i][k], &b[k][j]))
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn power<A: Arithmetic>(
+    arithmetic: &A,
+    mut base: Matrix<A::Value>,
+    mut exponent: u64,
+) -> Matrix<A::Value> {
+    let n = base.len();
+    let mut result = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| arithmetic.count(u64::from(i == j)))
+                .collect()
+        })
+        .collect();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = product(arithmetic, &result, &base);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = product(arithmetic, &base, &base);
+        }
+    }
+    result
+}
+
+/// How one day moves the fish between states: `day[i][j]` fish get to state
+/// `i` from every fish in state `j`.
+fn transition(cycle: &LifeCycle, shape: (usize, usize)) -> Matrix<u64> {
+    let n = shape.0 * shape.1;
+    let mut day = vec![vec![0; n]; n];
+    let moves = (0..n).flat_map(|from| {
+        cycle
+            .successors(shape, from)
+            .into_iter()
+            .map(move |to| (to, from))
+    });
+    for (to, from) in moves {
+        day[to][from] += 1;
+    }
+    day
+}
+
+/// Counts the fish after `days` like [`count_fish_after`](crate::count_fish_after),
+/// raising the daily transition to the power `days` by repeated squaring.
+/// The matrix has a row per timer value and, with a lifespan, per age and
+/// timer value, and each squaring takes the cube of its rows: a lifespan of
+/// 80 days already makes a million days take minutes, where
+/// [`population`](crate::population) is cheaper for lifespans longer than
+/// the days to count.
+pub fn count_fish<A: Arithmetic>(
+    init_state: &[u8],
+    days: u64,
+    cycle: &LifeCycle,
+    arithmetic: &A,
+) -> A::Value {
+    let shape = cycle.shape(init_state);
+    let day = transition(cycle, shape)
+        .into_iter()
+        .map(|row| row.into_iter().map(|x| arithmetic.count(x)).collect())
+        .collect();
+    let all_days = power(arithmetic, day, days);
+    // Initial fish are newborn, their states are their timers.
+    (0..shape.1).fold(arithmetic.count(0), |sum, j| {
+        let fish = init_state.iter().filter(|&&t| t as usize == j).count() as u64;
+        let descendants = all_days.iter().fold(arithmetic.count(0), |sum, row| {
+            arithmetic.add(&sum, &row[j])
+        });
+        arithmetic.add(&sum, &arithmetic.mul(&arithmetic.count(fish), &descendants))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_fish_after, population};
+
+    #[test]
+    fn matches_the_simulation() {
+        let school = [3, 4, 3, 1, 2, 0, 8, 6];
+        let lanternfish = &LifeCycle::LANTERNFISH;
+        for days in (0..300).step_by(7) {
+            let expected = count_fish_after(&school, days, 7);
+            let exact = count_fish(&school, days as u64, lanternfish, &Exact);
+            assert_eq!(exact, BigUint::from(expected));
+            let modulus = 1_000_000_007;
+            let modulo = count_fish(&school, days as u64, lanternfish, &Modulo::new(modulus));
+            assert_eq!(modulo, (expected % modulus as usize) as u64);
+        }
+        // The simulation's fish give birth every `day_to_mature` days and
+        // their newborns wait two more.
+        for day_to_mature in [1, 2, 4] {
+            let cycle = LifeCycle {
+                spawn_interval: day_to_mature,
+                newborn_delay: day_to_mature + 2,
+                lifespan: None,
+            };
+            assert_eq!(
+                count_fish(&[0, 1, 2, 1], 40, &cycle, &Exact),
+                BigUint::from(count_fish_after(&[0, 1, 2, 1], 40, day_to_mature))
+            );
+        }
+    }
+
+    #[test]
+    fn matches_the_population() {
+        let school = [3, 4, 3, 1, 2, 0, 8, 6];
+        let cycles = [(1, 1, None), (5, 2, Some(4)), (7, 9, Some(12))];
+        for (spawn_interval, newborn_delay, lifespan) in cycles {
+            let cycle = LifeCycle {
+                spawn_interval,
+                newborn_delay,
+                lifespan,
+            };
+            let series = population(&school, 40, &cycle, &Exact);
+            let total: BigUint = series.days[40].iter().sum();
+            assert_eq!(count_fish(&school, 40, &cycle, &Exact), total);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "counts need a modulus of at least 1")]
+    fn reject_modulo_zero() {
+        Modulo::new(0);
+    }
+
+    #[test]
+    fn far_future() {
+        let school = [3, 4, 3, 1, 2];
+        let modulus = 998_244_353;
+        let exact = count_fish(&school, 5000, &LifeCycle::LANTERNFISH, &Exact);
+        let modulo = count_fish(
+            &school,
+            5000,
+            &LifeCycle::LANTERNFISH,
+            &Modulo::new(modulus),
+        );
+        assert_eq!(BigUint::from(modulo), exact % modulus);
+        let far = count_fish(
+            &school,
+            1_000_000_000_000_000,
+            &LifeCycle::LANTERNFISH,
+            &Modulo::new(modulus),
+        );
+        assert!(far < modulus);
+    }
+}
@@ -0,0 +1,10 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The horizontal positions of `size` crabs, between 0 and 2000.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| rng.gen_range(0..2000).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
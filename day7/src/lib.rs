@@ -0,0 +1,91 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use itertools::Itertools;
+use std::cmp::max;
+use std::cmp::min;
+
+type FuelCounter = dyn Fn(&[(usize, &u16)], u16) -> usize;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input<'a> = Vec<u16>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<u16>, ParseError> {
+        let src = Source::new(Self::DAY, input);
+        input
+            .trim_end()
+            .split(',')
+            .map(|i| src.parse(i, "a crab position"))
+            .collect()
+    }
+    fn part1(crab_positions: &Vec<u16>) -> usize {
+        count_least_fuel_required(crab_positions, &count_fuel_required_p1)
+    }
+    fn part2(crab_positions: &Vec<u16>) -> usize {
+        count_least_fuel_required(crab_positions, &count_fuel_required_p2)
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day7::part1(&Day7::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    Day7::part2(&Day7::parse(input).unwrap())
+}
+
+fn count_least_fuel_required(
+    crab_positions: &[u16],
+    count_fuel: &FuelCounter,
+) -> usize {
+    let positions = crab_positions
+        .iter()
+        .sorted()
+        .dedup_with_count()
+        .collect_vec();
+    let (_, &min_position) = positions.first().unwrap();
+    let (_, &max_position) = positions.last().unwrap();
+    (min_position..=max_position).fold(usize::MAX, |acc, n| min(acc, count_fuel(&positions, n)))
+}
+
+fn count_fuel_required_p1(positions: &[(usize, &u16)], n: u16) -> usize {
+    positions.iter().fold(0_usize, |acc, (count, &p)| {
+        acc + usize::from(max(n, p) - min(n, p)) * count
+    })
+}
+
+fn count_fuel_required_p2(positions: &[(usize, &u16)], n: u16) -> usize {
+    positions.iter().fold(0_usize, |acc, (count, &p)| {
+        acc + (incremental_fuel(usize::from(max(n, p) - min(n, p))) * count)
+    })
+}
+
+fn incremental_fuel(n: usize) -> usize {
+    n * (1 + n) / 2
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn puzzle_1_working() {
+        let input = "16,1,2,0,4,2,7,1,2,14";
+        assert_eq!(puzzle_1(input), 37);
+    }
+
+    #[test]
+    fn parse_reports_bad_position() {
+        let error = Day7::parse("16,1,-2,0").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 6, "-2"));
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = "16,1,2,0,4,2,7,1,2,14";
+        assert_eq!(puzzle_2(input), 168);
+    }
+}
@@ -0,0 +1,38 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// `size` notes, each from a display with its own scrambled wiring.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            let mut wires: Vec<char> = "abcdefg".chars().collect();
+            wires.shuffle(&mut rng);
+            let pattern = |digit: usize, rng: &mut StdRng| {
+                let mut segments = DIGITS[digit]
+                    .bytes()
+                    .map(|b| wires[(b - b'a') as usize])
+                    .collect::<Vec<_>>();
+                segments.shuffle(rng);
+                segments.into_iter().collect::<String>()
+            };
+            let mut order: Vec<usize> = (0..10).collect();
+            order.shuffle(&mut rng);
+            let patterns = order
+                .into_iter()
+                .map(|d| pattern(d, &mut rng))
+                .collect::<Vec<_>>();
+            let output = (0..4)
+                .map(|_| {
+                    let digit = rng.gen_range(0..10);
+                    pattern(digit, &mut rng)
+                })
+                .collect::<Vec<_>>();
+            format!("{} | {}", patterns.join(" "), output.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,143 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input<'a> = Vec<Note<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Note<'_>>, ParseError> {
+        let src = Source::new(Self::DAY, input);
+        input.lines().map(|l| Note::parse(src, l)).collect()
+    }
+    fn part1(notes: &Vec<Note>) -> usize {
+        notes
+            .iter()
+            .map(|note| {
+                note.digits
+                    .iter()
+                    .filter(|digits| matches!(digits.len(), 2 | 3 | 4 | 7))
+                    .count()
+            })
+            .sum()
+    }
+    fn part2(notes: &Vec<Note>) -> usize {
+        notes.iter().map(decode).sum()
+    }
+}
+
+pub fn puzzle_1(input: &str) -> usize {
+    Day8::part1(&Day8::parse(input).unwrap())
+}
+
+pub fn puzzle_2(input: &str) -> usize {
+    Day8::part2(&Day8::parse(input).unwrap())
+}
+
+/// One entry of the notes: the ten unique signal patterns, then the four
+/// output digits.
+#[derive(Debug)]
+pub struct Note<'a> {
+    symbols: Vec<&'a str>,
+    digits: Vec<&'a str>,
+}
+
+impl<'a> Note<'a> {
+    fn parse(src: Source, line: &'a str) -> Result<Self, ParseError> {
+        let (symbols, digits) = line
+            .split_once('|')
+            .ok_or_else(|| src.missing(line, "`|`"))?;
+        let symbols: Vec<&str> = symbols.split_whitespace().collect();
+        let digits: Vec<&str> = digits.split_whitespace().collect();
+        if symbols.len() != 10 {
+            return Err(src.error(line, "ten signal patterns"));
+        }
+        if digits.len() != 4 {
+            return Err(src.error(line, "four output digits"));
+        }
+        Ok(Note { symbols, digits })
+    }
+}
+
+fn decode(note: &Note) -> usize {
+    let mut symbols_map = HashMap::<&str, u8>::new();
+    let mut digit_map = HashMap::<u8, &str>::new();
+
+    let symbols = note
+        .symbols
+        .iter()
+        .map(|s| s.chars().sorted().join(""))
+        .collect::<Vec<_>>();
+    let digits = note
+        .digits
+        .iter()
+        .map(|s| s.chars().sorted().join(""))
+        .collect::<Vec<_>>();
+    let find_symbol_by_len = |len: usize| symbols.iter().find(|&s| s.len() == len).unwrap();
+    // Simple mapping found by length
+    for (len, digit) in [(2, 1), (4, 4), (3, 7), (7, 8)] {
+        let symbol = find_symbol_by_len(len);
+        let d = digit;
+        symbols_map.insert(symbol.as_str(), d);
+        digit_map.insert(d, symbol.as_str());
+    }
+    symbols
+        .iter()
+        .filter(|&s| s.len() == 5 || s.len() == 6)
+        .map(|symbol| {
+            let one = digit_map.get(&1).unwrap();
+            let four = digit_map.get(&4).unwrap();
+            let check_one = symbol.chars().filter(|&c| one.contains(c)).count();
+            let check_four = symbol.chars().filter(|&c| four.contains(c)).count();
+            (symbol, symbol.len(), check_one, check_four)
+        })
+        .map(|res| match res {
+            (s, 5, 2, 3) => (s, 3),
+            (s, 5, 1, 2) => (s, 2),
+            (s, 5, 1, 3) => (s, 5),
+            (s, 6, 1, 3) => (s, 6),
+            (s, 6, 2, 4) => (s, 9),
+            (s, 6, 2, 3) => (s, 0),
+            (s, len, check_one, check_four) => panic!(
+                "unexpected pattern symbols: {} len: {} check_one: {} check_four: {} ",
+                s, len, check_one, check_four
+            ),
+        })
+        .for_each(|(s, d)| {
+            symbols_map.insert(s, d);
+        });
+    digits
+        .iter()
+        .map(|digit| symbols_map.get(digit.as_str()).unwrap_or(&1))
+        .fold(0, |acc, &curr| acc * 10 + curr as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 26);
+    }
+
+    #[test]
+    fn signal_parser_worker() {
+        let input = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |
+        fdgacbe cefdb cefbgd gcbe";
+        assert_eq!(decode(&Note::parse(Source::new(8, input), input).unwrap()), 8394)
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = fs::read_to_string("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 61229);
+    }
+}
@@ -0,0 +1,120 @@
+pub mod generator;
+use common::{ParseError, Solution, Source};
+use grid::{DenseGrid, Point};
+use itertools::Itertools;
+use std::{collections::{HashMap, HashSet}, fs};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input<'a> = DenseGrid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<DenseGrid<u8>, ParseError> {
+        parse(input)
+    }
+    fn part1(input: &DenseGrid<u8>) -> usize {
+        puzzle_1(input)
+    }
+    fn part2(input: &DenseGrid<u8>) -> usize {
+        puzzle_2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<DenseGrid<u8>, ParseError> {
+    DenseGrid::parse(Source::new(Day9::DAY, input), input.trim())
+}
+
+pub fn load(path: &str) -> Result<DenseGrid<u8>, ParseError> {
+    parse(&fs::read_to_string(path).expect("Fail to load input"))
+}
+
+pub fn puzzle_1(board: &DenseGrid<u8>) -> usize {
+    find_lowest_points(board)
+        .iter()
+        .fold(0_usize, |acc, &point| acc + board[point] as usize + 1)
+}
+
+/// Low points that share a basin all count it whole, so each basin is
+/// flooded once, from the first of its low points.
+pub fn puzzle_2(board: &DenseGrid<u8>) -> usize {
+    let mut basin_of = HashMap::<Point, usize>::new();
+    let mut sizes = Vec::new();
+    find_lowest_points(board)
+        .iter()
+        .map(|&point| {
+            let id = *basin_of.entry(point).or_insert_with(|| sizes.len());
+            if id == sizes.len() {
+                let basin = get_basion(board, point);
+                sizes.push(basin.len());
+                basin_of.extend(basin.into_iter().map(|p| (p, id)));
+            }
+            sizes[id]
+        })
+        .sorted()
+        .rev()
+        .take(3)
+        .product()
+}
+
+fn get_basion(board: &DenseGrid<u8>, point: Point) -> HashSet<Point> {
+    let mut set = HashSet::from([point]);
+    let mut pending = vec![point];
+    while let Some(point) = pending.pop() {
+        for n in board.neighbors4(point) {
+            if board[n] < 9 && set.insert(n) {
+                pending.push(n);
+            }
+        }
+    }
+    set
+}
+
+#[cfg(test)]
+fn count_basion(board: &DenseGrid<u8>, point: Point) -> usize {
+    get_basion(board, point).len()
+}
+
+fn find_lowest_points(board: &DenseGrid<u8>) -> Vec<Point> {
+    board
+        .points()
+        .filter(|&point| board.neighbors4(point).all(|n| board[n] > board[point]))
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle_1_working() {
+        let input = load("test_case").unwrap();
+        assert_eq!(puzzle_1(&input), 15);
+    }
+
+    #[test]
+    fn puzzle_2_working() {
+        let input = load("test_case").unwrap();
+        assert_eq!(puzzle_2(&input), 1134);
+    }
+
+    #[test]
+    fn count_basion_working_1() {
+        let board = load("test_case").unwrap();
+        assert_eq!(count_basion(&board, Point::new(1, 0)), 3)
+    }
+
+    #[test]
+    fn count_basion_working_2() {
+        let board = load("test_case").unwrap();
+        assert_eq!(count_basion(&board, Point::new(6, 4)), 9)
+    }
+
+    #[test]
+    fn single_row_board() {
+        let board = parse("2199943").unwrap();
+        assert_eq!(puzzle_1(&board), 2 + 4);
+    }
+}
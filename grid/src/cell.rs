@@ -0,0 +1,40 @@
+/// A grid cell written as a single character of the puzzle input.
+pub trait Cell: Sized {
+    /// What a cell looks like, reported by parse errors.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// A single digit, like heights, energy levels or risk levels.
+impl Cell for u8 {
+    const EXPECTED: &'static str = "a digit";
+
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+    fn to_char(&self) -> char {
+        char::from_digit(*self as u32, 10).unwrap_or('+')
+    }
+}
+
+/// A lit `#` or dark `.` pixel.
+impl Cell for bool {
+    const EXPECTED: &'static str = "`#` or `.`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{Cell, Point};
+use common::{ParseError, Source};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid storing every cell, from `(0, 0)` to
+/// `(width - 1, height - 1)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> DenseGrid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+            .map(&mut f)
+            .collect();
+        DenseGrid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        let x = usize::try_from(p.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(p.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x as i64, y as i64)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The orthogonal neighbors of `p` inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(move |&n| self.contains(n))
+    }
+
+    /// The orthogonal and diagonal neighbors of `p` inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(move |&n| self.contains(n))
+    }
+}
+
+impl<T: Cell> DenseGrid<T> {
+    /// Parses the lines of `text`, a slice of `src`, one cell per character.
+    pub fn parse(src: Source, text: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in text.lines() {
+            for (i, c) in line.char_indices() {
+                let cell = T::from_char(c)
+                    .ok_or_else(|| src.error(&line[i..i + c.len_utf8()], T::EXPECTED))?;
+                cells.push(cell);
+            }
+            let row_width = cells.len() - height * width.unwrap_or(0);
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(src.error(line, format!("a row of {} cells", width)))
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+        Ok(DenseGrid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> Index<Point> for DenseGrid<T> {
+    type Output = T;
+    fn index(&self, p: Point) -> &T {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for DenseGrid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of a {}x{} grid", p, width, height),
+        }
+    }
+}
+
+impl<T: Cell> fmt::Display for DenseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> DenseGrid<u8> {
+        DenseGrid::parse(Source::new(0, text), text).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = grid("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn parse_reports_ragged_rows() {
+        let text = "123\n45\n";
+        let error = DenseGrid::<u8>::parse(Source::new(0, text), text).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a row of 3 cells");
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = grid("12\n34\n");
+        let corner = grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn single_row_neighbors() {
+        let grid = grid("123");
+        let middle = grid.neighbors4(Point::new(1, 0)).collect::<Vec<_>>();
+        assert_eq!(middle, vec![Point::new(0, 0), Point::new(2, 0)]);
+    }
+}
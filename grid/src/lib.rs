@@ -0,0 +1,9 @@
+mod cell;
+mod dense;
+mod point;
+mod sparse;
+
+pub use cell::Cell;
+pub use dense::DenseGrid;
+pub use point::Point;
+pub use sparse::SparseGrid;
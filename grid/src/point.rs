@@ -0,0 +1,56 @@
+use std::ops::{Add, Sub};
+
+const NEIGHBORS_4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBORS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A position on a grid, `x` growing rightwards and `y` downwards.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The orthogonal neighbors, in reading order. Grids filter out the ones
+    /// outside of their bounds.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        NEIGHBORS_4.iter().map(move |&(x, y)| self + Point::new(x, y))
+    }
+
+    /// The orthogonal and diagonal neighbors, in reading order.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        NEIGHBORS_8.iter().map(move |&(x, y)| self + Point::new(x, y))
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
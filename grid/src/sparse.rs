@@ -0,0 +1,169 @@
+use crate::{Cell, Point};
+use common::{ParseError, Source};
+use std::{collections::BTreeMap, fmt};
+
+/// An unbounded grid storing only the cells it was given, for grids that
+/// are mostly empty or that grow in every direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: BTreeMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// The cell at `p`, inserting `T::default()` when it is missing.
+    pub fn entry(&mut self, p: Point) -> &mut T
+    where
+        T: Default,
+    {
+        self.cells.entry(p).or_default()
+    }
+
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// The top left and bottom right corners of the stored cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.cells.keys().fold(None, |bounds, &p| match bounds {
+            None => Some((p, p)),
+            Some((min, max)) => Some((
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )),
+        })
+    }
+
+    /// The stored orthogonal neighbors of `p`.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(move |&n| self.contains(n))
+    }
+
+    /// The stored orthogonal and diagonal neighbors of `p`.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(move |&n| self.contains(n))
+    }
+
+    /// Draws every point within `bounds`, one line per row.
+    pub fn render(&self, bounds: (Point, Point), draw: impl Fn(Option<&T>) -> char) -> String {
+        let (min, max) = bounds;
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                out.push(draw(self.get(Point::new(x, y))));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T: Cell + Default + PartialEq> SparseGrid<T> {
+    /// Parses the lines of `text`, a slice of `src`, one cell per character.
+    /// Cells equal to `T::default()`, like `.` or `0`, are left out.
+    pub fn parse(src: Source, text: &str) -> Result<Self, ParseError> {
+        let mut grid = SparseGrid::new();
+        for (y, line) in text.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let cell = T::from_char(c)
+                    .ok_or_else(|| src.error(&line[i..i + c.len_utf8()], T::EXPECTED))?;
+                if cell != T::default() {
+                    grid.insert(Point::new(x as i64, y as i64), cell);
+                }
+            }
+        }
+        Ok(grid)
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Renders the stored cells within their bounds, missing ones as
+/// `T::default()`.
+impl<T: Cell + Default> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(bounds) = self.bounds() {
+            let blank = T::default().to_char();
+            let text = self.render(bounds, |cell| cell.map_or(blank, Cell::to_char));
+            write!(f, "{}", text)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keeps_lit_pixels() {
+        let text = "#..\n.#.\n";
+        let grid = SparseGrid::<bool>::parse(Source::new(0, text), text).unwrap();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point::new(0, 0), Point::new(1, 1))));
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+    }
+
+    #[test]
+    fn neighbors_are_stored_cells() {
+        let grid: SparseGrid<bool> = [Point::new(-1, 0), Point::new(1, 1)]
+            .into_iter()
+            .map(|p| (p, true))
+            .collect();
+        assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 1);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 2);
+    }
+}